@@ -0,0 +1,248 @@
+use crate::solution::{DaySolution, Part, Unsolved};
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+/// Default location of the answers file, next to the `input/` directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Accepted answers of solved puzzles, keyed by day and part.
+///
+/// The answers are stored in a small subset of TOML, one table per day:
+/// ```toml
+/// [07]
+/// part1 = "3749"
+/// part2 = "11387"
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct AnswerStore {
+    answers: BTreeMap<(String, Part), String>,
+}
+
+impl AnswerStore {
+    pub fn new() -> Self {
+        AnswerStore::default()
+    }
+
+    /// Load the answers from a file, a missing file means there are no answers yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(AnswerStore::new());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Can't read {}", path.display()))?;
+        AnswerStore::parse(&content).with_context(|| format!("Invalid {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_toml())
+            .with_context(|| format!("Can't write {}", path.display()))
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut store = AnswerStore::new();
+        let mut current_day: Option<String> = None;
+
+        for (line_index, line) in content.lines().enumerate() {
+            let line_number = line_index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(day) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let day = day.trim().trim_matches('"');
+                if day.is_empty() || !day.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(anyhow!("Line {}: invalid day '{}'", line_number, day));
+                }
+                current_day = Some(format!("{:0>2}", day));
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| anyhow!("Line {}: expected `partN = \"answer\"`", line_number))?;
+            let day = current_day
+                .as_ref()
+                .ok_or_else(|| anyhow!("Line {}: answer outside of a [day] table", line_number))?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                key => return Err(anyhow!("Line {}: unknown key '{}'", line_number, key)),
+            };
+            let value = value.trim();
+            let answer = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+            store.set(day, part, answer);
+        }
+
+        Ok(store)
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        let mut previous_day: Option<&str> = None;
+        for ((day, part), answer) in &self.answers {
+            if previous_day != Some(day) {
+                if previous_day.is_some() {
+                    toml.push('\n');
+                }
+                toml.push_str(&format!("[{}]\n", day));
+                previous_day = Some(day);
+            }
+            toml.push_str(&format!("part{} = \"{}\"\n", part, answer));
+        }
+        toml
+    }
+
+    pub fn get(&self, day: &str, part: Part) -> Option<&str> {
+        self.answers
+            .get(&(format!("{:0>2}", day), part))
+            .map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: &str, part: Part, answer: &str) {
+        self.answers
+            .insert((format!("{:0>2}", day), part), answer.to_string());
+    }
+}
+
+/// Outcome of re-running a part and comparing it with its accepted answer.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    /// The answer is the same as the accepted one.
+    Pass,
+    /// The part returned an answer different from the accepted one.
+    Changed { expected: String, actual: String },
+    /// The part returned an error.
+    Fail(String),
+    /// The part works, but there is no accepted answer to compare with.
+    Unverified(String),
+    /// The part is not solved yet.
+    Unsolved,
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Changed { .. } | Verdict::Fail(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Changed { expected, actual } => {
+                write!(f, "CHANGED: expected {}, got {}", expected, actual)
+            }
+            Verdict::Fail(e) => write!(f, "FAIL: {}", e),
+            Verdict::Unverified(actual) => write!(f, "unverified, got {}", actual),
+            Verdict::Unsolved => write!(f, "not solved yet"),
+        }
+    }
+}
+
+/// Solve a part of the day and compare the answer with the accepted one.
+pub fn verify_part(
+    day: &DaySolution,
+    part: Part,
+    puzzle_input: &str,
+    expected: Option<&str>,
+) -> Verdict {
+    match day.solve(part, &mut puzzle_input.as_bytes()) {
+        Result::Ok(actual) => match expected {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Changed {
+                expected: expected.to_string(),
+                actual,
+            },
+            None => Verdict::Unverified(actual),
+        },
+        Err(e) if e.is::<Unsolved>() => Verdict::Unsolved,
+        Err(e) => Verdict::Fail(format!("{:#}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    const ANSWERS: &str = "\
+# Accepted answers
+[01]
+part1 = \"11\"
+part2 = \"31\"
+
+[7]
+part1 = \"3749\"
+";
+
+    const DAY_01_EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn parse_answers() {
+        let store = AnswerStore::parse(ANSWERS).unwrap();
+        assert_eq!(store.get("01", Part::One), Some("11"));
+        assert_eq!(store.get("1", Part::Two), Some("31"));
+        assert_eq!(store.get("07", Part::One), Some("3749"));
+        assert_eq!(store.get("07", Part::Two), None);
+    }
+
+    #[test]
+    fn answers_survive_round_trip() {
+        let store = AnswerStore::parse(ANSWERS).unwrap();
+        let reparsed = AnswerStore::parse(&store.to_toml()).unwrap();
+        assert_eq!(store, reparsed);
+    }
+
+    #[test]
+    fn reject_answer_outside_of_day() {
+        let result = AnswerStore::parse("part1 = \"1\"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn reject_unknown_key() {
+        let error = AnswerStore::parse("[01]\npart3 = \"1\"").unwrap_err();
+        assert!(error.to_string().contains("Line 2"));
+    }
+
+    #[test]
+    fn verify_matching_answer() {
+        let day = days::find("01").unwrap();
+        let verdict = verify_part(&day, Part::One, DAY_01_EXAMPLE, Some("11"));
+        assert_eq!(verdict, Verdict::Pass);
+    }
+
+    #[test]
+    fn verify_changed_answer() {
+        let day = days::find("01").unwrap();
+        let verdict = verify_part(&day, Part::Two, DAY_01_EXAMPLE, Some("30"));
+        assert!(verdict.is_failure());
+        assert_eq!(
+            verdict,
+            Verdict::Changed {
+                expected: "30".to_string(),
+                actual: "31".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn verify_without_accepted_answer() {
+        let day = days::find("01").unwrap();
+        let verdict = verify_part(&day, Part::One, DAY_01_EXAMPLE, None);
+        assert_eq!(verdict, Verdict::Unverified("11".to_string()));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::solution::{Part, Solution};
+use anyhow::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Runs which are not measured, to warm up caches and the branch predictor.
+    pub warmup: usize,
+    /// Measured runs.
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+/// Summary of the measured durations of a piece of code.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "At least one sample is needed");
+        samples.sort();
+
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        // Nearest-rank percentile
+        let p95_index = (n * 95).div_ceil(100) - 1;
+
+        Stats {
+            iterations: n,
+            min: samples[0],
+            median,
+            p95: samples[p95_index],
+        }
+    }
+}
+
+/// Time `f` over warm-up plus measured runs.
+///
+/// It can be used to compare two approaches to the same problem directly, e.g.
+/// ```no_run
+/// use adv_code_2024::bench::{measure, BenchConfig};
+///
+/// let config = BenchConfig::default();
+/// let naive = measure(&config, || (0..1_000_u64).map(|x| x * x).sum::<u64>());
+/// let closed_form = measure(&config, || 999 * 1000 * 1999 / 6_u64);
+/// println!("naive: {:?}, closed form: {:?}", naive.median, closed_form.median);
+/// ```
+pub fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let samples = (0..config.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            // Dropping the result isn't part of the measured code
+            let result = black_box(f());
+            let elapsed = start.elapsed();
+            drop(result);
+            elapsed
+        })
+        .collect();
+    Stats::from_samples(samples)
+}
+
+/// Timings of a part of a day, with parsing and solving measured separately.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartBenchmark {
+    pub day: &'static str,
+    pub part: Part,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Benchmark a part of the day against an in-memory puzzle input.
+///
+/// Fails when the input can't be parsed or the part returns an error
+/// (including [`crate::solution::Unsolved`]), before any timing is done.
+pub fn benchmark<S: Solution>(
+    puzzle_input: &str,
+    part: Part,
+    config: &BenchConfig,
+) -> Result<PartBenchmark> {
+    let input = S::parse(&mut puzzle_input.as_bytes())?;
+    match part {
+        Part::One => S::solve_part1(&input).map(drop)?,
+        Part::Two => S::solve_part2(&input).map(drop)?,
+    }
+
+    let parse_stats = measure(config, || S::parse(&mut puzzle_input.as_bytes()));
+    let solve_stats = match part {
+        Part::One => measure(config, || S::solve_part1(&input)),
+        Part::Two => measure(config, || S::solve_part2(&input)),
+    };
+
+    Ok(PartBenchmark {
+        day: S::DAY,
+        part,
+        parse: parse_stats,
+        solve: solve_stats,
+    })
+}
+
+pub fn to_table(benchmarks: &[PartBenchmark]) -> String {
+    let mut table = format!(
+        "{:<4} {:<5} {:<6} {:>12} {:>12} {:>12}\n",
+        "Day", "Part", "Stage", "min", "median", "p95"
+    );
+    for benchmark in benchmarks {
+        for (stage, stats) in [("parse", &benchmark.parse), ("solve", &benchmark.solve)] {
+            table.push_str(&format!(
+                "{:<4} {:<5} {:<6} {:>12} {:>12} {:>12}\n",
+                benchmark.day,
+                benchmark.part,
+                stage,
+                format!("{:.1?}", stats.min),
+                format!("{:.1?}", stats.median),
+                format!("{:.1?}", stats.p95),
+            ));
+        }
+    }
+    table
+}
+
+/// Serialize the benchmarks as a JSON array, with durations in nanoseconds.
+pub fn to_json(benchmarks: &[PartBenchmark]) -> String {
+    fn stats_to_json(stats: &Stats) -> String {
+        format!(
+            "{{\"min_ns\": {}, \"median_ns\": {}, \"p95_ns\": {}}}",
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.p95.as_nanos()
+        )
+    }
+
+    let entries: Vec<String> = benchmarks
+        .iter()
+        .map(|benchmark| {
+            format!(
+                "  {{\"day\": \"{}\", \"part\": {}, \"iterations\": {}, \"parse\": {}, \"solve\": {}}}",
+                benchmark.day,
+                benchmark.part,
+                benchmark.solve.iterations,
+                stats_to_json(&benchmark.parse),
+                stats_to_json(&benchmark.solve)
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day01::Day01;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect()
+    }
+
+    #[test]
+    fn stats_of_odd_number_of_samples() {
+        let stats = Stats::from_samples(millis(&[5, 1, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn stats_of_even_number_of_samples() {
+        let stats = Stats::from_samples(millis(&[4, 1, 2, 3]));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+    }
+
+    #[test]
+    fn p95_ignores_the_slowest_outlier() {
+        let mut samples: Vec<u64> = vec![1; 19];
+        samples.push(100);
+        let stats = Stats::from_samples(millis(&samples));
+        assert_eq!(stats.p95, Duration::from_millis(1));
+    }
+
+    #[test]
+    fn measure_runs_warmup_and_iterations() {
+        let mut calls = 0;
+        let config = BenchConfig {
+            warmup: 2,
+            iterations: 5,
+        };
+        let stats = measure(&config, || calls += 1);
+        assert_eq!(calls, 7);
+        assert_eq!(stats.iterations, 5);
+    }
+
+    #[test]
+    fn benchmark_a_day() {
+        let config = BenchConfig {
+            warmup: 0,
+            iterations: 3,
+        };
+        let benchmark = benchmark::<Day01>("3   4\n4   3\n", Part::One, &config).unwrap();
+        assert_eq!(benchmark.day, "01");
+        assert_eq!(benchmark.parse.iterations, 3);
+
+        let json = to_json(&[benchmark]);
+        assert!(json.starts_with("[\n  {\"day\": \"01\", \"part\": 1, \"iterations\": 3"));
+    }
+
+    #[test]
+    fn benchmark_fails_on_unsolved_part() {
+        use crate::days::day15::Day15;
+        let result = benchmark::<Day15>("#@.#\n\n<>\n", Part::Two, &BenchConfig::default());
+        assert!(result.is_err());
+    }
+}
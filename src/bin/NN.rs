@@ -1,50 +1,53 @@
-use adv_code_2024::*;
+use adv_code_2024::solution::{run, Solution};
 use anyhow::*;
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
-const DAY: &str = "NN"; // TODO: Fill the day
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
+struct DayNN;
 
-const TEST: &str = "\
-<TEST-INPUT>
-"; // TODO: Add the test input
+impl Solution for DayNN {
+    const DAY: &'static str = "NN"; // TODO: Fill the day
 
-fn main() -> Result<()> {
-    start_day(DAY);
+    type Input = Vec<String>; // TODO: Choose the type of the parsed input
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    //region Part 1
-    println!("=== Part 1 ===");
+    fn parse<R: BufRead>(reader: &mut R) -> Result<Vec<String>> {
+        // TODO: Parse the puzzle input
+        Ok(reader.lines().map_while(Result::ok).collect())
+    }
 
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
+    fn solve_part1(input: &Vec<String>) -> Result<usize> {
         // TODO: Solve Part 1 of the puzzle
-        let answer = reader.lines().flatten().count();
+        let answer = input.len();
         Ok(answer)
     }
 
-    // TODO: Set the expected answer for the test input
-    assert_eq!(0, part1(BufReader::new(TEST.as_bytes()))?);
+    // fn solve_part2(input: &Vec<String>) -> Result<usize> {
+    //     Ok(0)
+    // }
+}
+
+fn main() -> Result<()> {
+    run::<DayNN>()
+}
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-    //endregion
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adv_code_2024::solve_example;
 
-    //region Part 2
-    // println!("\n=== Part 2 ===");
-    //
-    // fn part2<R: BufRead>(reader: R) -> Result<usize> {
-    //     Ok(0)
+    const TEST: &str = "\
+<TEST-INPUT>
+"; // TODO: Add the test input
+
+    #[test]
+    fn part1_example() {
+        // TODO: Set the expected answer for the test input
+        assert_eq!(1, solve_example(DayNN::part1, TEST));
+    }
+
+    // #[test]
+    // fn part2_example() {
+    //     assert_eq!(0, solve_example(DayNN::part2, TEST));
     // }
-    //
-    // assert_eq!(0, part2(BufReader::new(TEST.as_bytes()))?);
-    //
-    // let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    // let result = time_snippet!(part2(input_file)?);
-    // println!("Result = {}", result);
-    //endregion
-
-    Ok(())
 }
@@ -0,0 +1,89 @@
+use crate::option_value;
+use adv_code_2024::bench::{self, BenchConfig};
+use adv_code_2024::days;
+use adv_code_2024::solution::{DaySolution, Part, Unsolved};
+use anyhow::*;
+use std::path::Path;
+use std::process::ExitCode;
+
+struct BenchArgs {
+    days: Vec<DaySolution>,
+    parts: Vec<Part>,
+    config: BenchConfig,
+    json: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<BenchArgs> {
+    let mut args = args.iter();
+
+    let mut days = days::all();
+    let mut parts = Part::BOTH.to_vec();
+    let mut config = BenchConfig::default();
+    let mut json = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => parts = vec![option_value(arg, &mut args)?.parse()?],
+            "--warmup" => config.warmup = option_value(arg, &mut args)?.parse()?,
+            "--iterations" | "-n" => config.iterations = option_value(arg, &mut args)?.parse()?,
+            "--json" => json = true,
+            "all" => days = days::all(),
+            day if !day.starts_with('-') => {
+                let day =
+                    days::find(day).ok_or_else(|| anyhow!("Day '{}' is not registered", day))?;
+                days = vec![day];
+            }
+            _ => return Err(anyhow!("Unexpected argument '{}'", arg)),
+        }
+    }
+    if config.iterations == 0 {
+        return Err(anyhow!("At least one iteration is needed"));
+    }
+
+    Ok(BenchArgs {
+        days,
+        parts,
+        config,
+        json,
+    })
+}
+
+/// Benchmark parsing and solving of the days against their personal inputs.
+pub fn bench(args: &[String]) -> Result<ExitCode> {
+    let args = parse_args(args)?;
+
+    let mut benchmarks = Vec::new();
+    let mut n_failed = 0;
+    for day in &args.days {
+        let input_path = day.input_path();
+        if !Path::new(&input_path).exists() {
+            eprintln!("Day {}: skipped, there is no {}", day.day, input_path);
+            continue;
+        }
+        let puzzle_input = std::fs::read_to_string(&input_path)
+            .with_context(|| format!("Can't read {}", input_path))?;
+
+        for part in &args.parts {
+            match (day.bench)(&puzzle_input, *part, &args.config) {
+                Result::Ok(benchmark) => benchmarks.push(benchmark),
+                Err(e) if e.is::<Unsolved>() => {
+                    eprintln!("Day {} part {}: not solved yet", day.day, part)
+                }
+                Err(e) => {
+                    eprintln!("Day {} part {}: FAILED: {:#}", day.day, part, e);
+                    n_failed += 1;
+                }
+            }
+        }
+    }
+
+    if args.json {
+        print!("{}", bench::to_json(&benchmarks));
+    } else {
+        print!("{}", bench::to_table(&benchmarks));
+    }
+
+    if n_failed > 0 {
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
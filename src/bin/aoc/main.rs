@@ -0,0 +1,71 @@
+//! Single command-line runner for all registered days.
+//!
+//! ```text
+//! aoc run 07 --part 2 --input path/to/file
+//! aoc run all
+//! cat input/07.txt | aoc run 7 --input -
+//! aoc verify --lock
+//! aoc bench 11 --iterations 50 --json
+//! aoc new-day 16 --example example.txt --answer 42
+//! ```
+
+mod bench;
+mod new_day;
+mod run;
+mod verify;
+
+use anyhow::*;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: aoc <COMMAND>
+
+Commands:
+  run <DAY|all> [--part 1|2] [--input <FILE>|-]
+        Solve a day, or every registered day. By default both parts run against input/NN.txt.
+        With `--input -` the puzzle input is read from stdin.
+  verify [DAY|all] [--lock] [--answers <FILE>]
+        Re-run the days against input/NN.txt and compare the answers with answers.toml.
+        Reports pass, CHANGED or FAIL per part. With `--lock` new answers are saved as accepted.
+  bench [DAY|all] [--part 1|2] [--warmup <N>] [--iterations <N>] [--json]
+        Time parsing and solving separately against input/NN.txt and report min/median/p95,
+        as a table or, with `--json`, as JSON.
+  new-day <DAY> [--example <FILE> [--answer <ANSWER>]]
+        Create src/days/dayNN.rs from the src/bin/NN.rs template, register it, add src/bin/NN.rs
+        and an empty input/NN.txt. With an example, the Part 1 test is generated ready to run.
+  help  Print this message
+
+Exit codes: 0 when every part succeeded, 1 when any part failed or changed, 2 on invalid usage.
+";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run::run(&args[1..]),
+        Some("verify") => verify::verify(&args[1..]),
+        Some("bench") => bench::bench(&args[1..]),
+        Some("new-day") => new_day::new_day(&args[1..]),
+        Some("help" | "--help" | "-h") => {
+            print!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Some(command) => Err(anyhow!("Unknown command '{}'", command)),
+        None => Err(anyhow!("Missing command")),
+    };
+
+    match result {
+        Result::Ok(exit_code) => exit_code,
+        Err(e) => {
+            eprintln!("Error: {:#}\n\n{}", e, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Take the value following an option, e.g. `2` in `--part 2`.
+fn option_value<'a>(option: &str, args: &mut impl Iterator<Item = &'a String>) -> Result<&'a str> {
+    args.next()
+        .map(String::as_str)
+        .ok_or_else(|| anyhow!("Option '{}' requires a value", option))
+}
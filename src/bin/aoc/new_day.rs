@@ -0,0 +1,76 @@
+use crate::option_value;
+use adv_code_2024::scaffold::{self, Example};
+use adv_code_2024::solution::input_path;
+use anyhow::*;
+use std::path::Path;
+use std::process::ExitCode;
+
+const DAYS_MOD: &str = "src/days/mod.rs";
+
+/// Create `src/days/dayNN.rs`, `src/bin/NN.rs` and an empty `input/NN.txt` for a new day,
+/// and register the day in `src/days/mod.rs`.
+pub fn new_day(args: &[String]) -> Result<ExitCode> {
+    let mut args = args.iter();
+
+    let day = args
+        .next()
+        .ok_or_else(|| anyhow!("Missing day to create, e.g. `aoc new-day 16`"))?;
+    let day = scaffold::day_number(day)?;
+
+    let mut example_file = None;
+    let mut answer = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--example" => example_file = Some(option_value(arg, &mut args)?),
+            "--answer" => answer = Some(option_value(arg, &mut args)?),
+            _ => return Err(anyhow!("Unexpected argument '{}'", arg)),
+        }
+    }
+    if answer.is_some() && example_file.is_none() {
+        return Err(anyhow!(
+            "`--answer` needs an example, pass it with `--example <FILE>`"
+        ));
+    }
+
+    let example_input = match example_file {
+        Some(path) => {
+            Some(std::fs::read_to_string(path).with_context(|| format!("Can't read {}", path))?)
+        }
+        None => None,
+    };
+    let example = example_input
+        .as_deref()
+        .map(|input| Example { input, answer });
+
+    let module_path = format!("src/days/day{}.rs", day);
+    let binary_path = format!("src/bin/{}.rs", day);
+    for path in [&module_path, &binary_path] {
+        if Path::new(path).exists() {
+            return Err(anyhow!("{} already exists", path));
+        }
+    }
+
+    let days_mod = std::fs::read_to_string(DAYS_MOD).with_context(|| {
+        format!(
+            "Can't read {}, run the command from the repository root",
+            DAYS_MOD
+        )
+    })?;
+    let days_mod = scaffold::register_day(&days_mod, &day)?;
+    let module = scaffold::day_module(&day, example.as_ref())?;
+
+    std::fs::write(&module_path, module)?;
+    println!("Created {}", module_path);
+    std::fs::write(&binary_path, scaffold::day_binary(&day))?;
+    println!("Created {}", binary_path);
+    std::fs::write(DAYS_MOD, days_mod)?;
+    println!("Registered day {} in {}", day, DAYS_MOD);
+
+    let input_path = input_path(&day);
+    if !Path::new(&input_path).exists() {
+        std::fs::write(&input_path, "")?;
+        println!("Created empty {}", input_path);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
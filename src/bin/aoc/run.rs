@@ -0,0 +1,130 @@
+use crate::option_value;
+use adv_code_2024::days;
+use adv_code_2024::solution::{DaySolution, Part, Unsolved};
+use anyhow::*;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+enum Input {
+    Default,
+    Stdin,
+    File(PathBuf),
+}
+
+struct RunArgs {
+    days: Vec<DaySolution>,
+    all_days: bool,
+    parts: Vec<Part>,
+    input: Input,
+}
+
+fn parse_args(args: &[String]) -> Result<RunArgs> {
+    let mut args = args.iter();
+
+    let target = args
+        .next()
+        .ok_or_else(|| anyhow!("Missing day to run, e.g. `aoc run 07` or `aoc run all`"))?;
+    let all_days = target == "all";
+    let days = if all_days {
+        days::all()
+    } else {
+        let day =
+            days::find(target).ok_or_else(|| anyhow!("Day '{}' is not registered", target))?;
+        vec![day]
+    };
+
+    let mut parts = Part::BOTH.to_vec();
+    let mut input = Input::Default;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => parts = vec![option_value(arg, &mut args)?.parse()?],
+            "--input" | "-i" => {
+                input = match option_value(arg, &mut args)? {
+                    "-" => Input::Stdin,
+                    path => Input::File(PathBuf::from(path)),
+                }
+            }
+            _ => return Err(anyhow!("Unexpected argument '{}'", arg)),
+        }
+    }
+
+    if all_days && !matches!(input, Input::Default) {
+        return Err(anyhow!(
+            "`--input` can't be used with `all`, every day reads its own input"
+        ));
+    }
+
+    Ok(RunArgs {
+        days,
+        all_days,
+        parts,
+        input,
+    })
+}
+
+pub fn run(args: &[String]) -> Result<ExitCode> {
+    let args = parse_args(args)?;
+
+    let mut n_failed = 0;
+    for day in &args.days {
+        println!("Day {}", day.day);
+
+        let puzzle_input = match read_input(day, &args.input) {
+            Result::Ok(puzzle_input) => puzzle_input,
+            // Running everything shouldn't fail just because some personal inputs are missing
+            Err(_) if args.all_days && !Path::new(&day.input_path()).exists() => {
+                println!("  skipped, there is no {}", day.input_path());
+                continue;
+            }
+            Err(e) => {
+                eprintln!("  FAILED: {:#}", e);
+                n_failed += 1;
+                continue;
+            }
+        };
+
+        for part in &args.parts {
+            let start = Instant::now();
+            let answer = day.solve(*part, &mut puzzle_input.as_bytes());
+            let duration = start.elapsed();
+
+            match answer {
+                Result::Ok(answer) => println!("  Part {}: {}  ({:?})", part, answer, duration),
+                // An unsolved part only fails when it's the one part of the one day asked for
+                Err(e) if e.is::<Unsolved>() && (args.days.len() > 1 || args.parts.len() > 1) => {
+                    println!("  Part {}: not solved yet", part)
+                }
+                Err(e) => {
+                    eprintln!("  Part {}: FAILED: {:#}", part, e);
+                    n_failed += 1;
+                }
+            }
+        }
+    }
+
+    if n_failed > 0 {
+        eprintln!("{} failure(s)", n_failed);
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn read_input(day: &DaySolution, input: &Input) -> Result<String> {
+    match input {
+        Input::Default => read_file(Path::new(&day.input_path())),
+        Input::File(path) => read_file(path),
+        Input::Stdin => {
+            let mut puzzle_input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut puzzle_input)
+                .context("Can't read the input from stdin")?;
+            Ok(puzzle_input)
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("Can't read {}", path.display()))
+}
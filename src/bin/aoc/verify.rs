@@ -0,0 +1,97 @@
+use crate::option_value;
+use adv_code_2024::answers::{verify_part, AnswerStore, Verdict, ANSWERS_FILE};
+use adv_code_2024::days;
+use adv_code_2024::solution::{DaySolution, Part};
+use anyhow::*;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+struct VerifyArgs {
+    days: Vec<DaySolution>,
+    lock: bool,
+    answers_file: PathBuf,
+}
+
+fn parse_args(args: &[String]) -> Result<VerifyArgs> {
+    let mut args = args.iter();
+
+    let mut days = days::all();
+    let mut lock = false;
+    let mut answers_file = PathBuf::from(ANSWERS_FILE);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--lock" => lock = true,
+            "--answers" => answers_file = PathBuf::from(option_value(arg, &mut args)?),
+            "all" => days = days::all(),
+            day if !day.starts_with('-') => {
+                let day =
+                    days::find(day).ok_or_else(|| anyhow!("Day '{}' is not registered", day))?;
+                days = vec![day];
+            }
+            _ => return Err(anyhow!("Unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(VerifyArgs {
+        days,
+        lock,
+        answers_file,
+    })
+}
+
+/// Re-run the days against their personal inputs and compare with the accepted answers.
+///
+/// With `--lock`, answers of parts which don't have an accepted answer yet are saved.
+pub fn verify(args: &[String]) -> Result<ExitCode> {
+    let args = parse_args(args)?;
+    let mut store = AnswerStore::load(&args.answers_file)?;
+
+    let mut n_passed = 0;
+    let mut n_changed = 0;
+    let mut n_failed = 0;
+    let mut n_locked = 0;
+    for day in &args.days {
+        let input_path = day.input_path();
+        if !Path::new(&input_path).exists() {
+            println!("Day {}: skipped, there is no {}", day.day, input_path);
+            continue;
+        }
+        let puzzle_input = std::fs::read_to_string(&input_path)
+            .with_context(|| format!("Can't read {}", input_path))?;
+
+        println!("Day {}", day.day);
+        for part in Part::BOTH {
+            let verdict = verify_part(day, part, &puzzle_input, store.get(day.day, part));
+            println!("  Part {}: {}", part, verdict);
+
+            match verdict {
+                Verdict::Pass => n_passed += 1,
+                Verdict::Unverified(answer) if args.lock => {
+                    store.set(day.day, part, &answer);
+                    n_locked += 1;
+                }
+                Verdict::Changed { .. } => n_changed += 1,
+                Verdict::Fail(_) => n_failed += 1,
+                _ => {}
+            }
+        }
+    }
+
+    if n_locked > 0 {
+        store.save(&args.answers_file)?;
+        println!(
+            "Locked {} new answer(s) in {}",
+            n_locked,
+            args.answers_file.display()
+        );
+    }
+    println!(
+        "{} passed, {} changed, {} failed",
+        n_passed, n_changed, n_failed
+    );
+
+    if n_changed + n_failed > 0 {
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
@@ -1,109 +1,544 @@
-use anyhow::*;
+use crate::parse::{Line, ParseError};
+use std::fmt::{Display, Formatter};
 use std::io::BufRead;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoardError {
+    /// The point is outside of the board of the given size.
+    OutOfBounds {
+        point: Point,
+        n_rows: usize,
+        n_cols: usize,
+    },
+    /// A row has a different length than the first one.
+    RaggedRow {
+        row: usize,
+        len: usize,
+        n_cols: usize,
+    },
+}
+
+impl Display for BoardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BoardError::OutOfBounds {
+                point,
+                n_rows,
+                n_cols,
+            } => write!(
+                f,
+                "Point (x: {}, y: {}) is out of bounds of the {}x{} board",
+                point.x, point.y, n_rows, n_cols
+            ),
+            BoardError::RaggedRow { row, len, n_cols } => write!(
+                f,
+                "Row {} has {} columns, but the board has {}",
+                row, len, n_cols
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BoardError {}
+
+/// Rectangular grid stored row by row in one contiguous vector.
+///
+/// Points are `x` = column and `y` = row. Negative coordinates are outside of the board,
+/// so they are rejected instead of wrapping around into a huge index.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Board<T> {
-    pub board: Vec<Vec<T>>,
+    cells: Vec<T>,
     pub n_rows: usize,
     pub n_cols: usize,
 }
 
 impl<T> Board<T> {
-    pub fn new(board: Vec<Vec<T>>) -> Self {
-        let n_rows = board.len();
-        let n_cols = board[0].len();
+    /// Panics when the rows don't have the same length, see [`Board::try_new`].
+    pub fn new(rows: Vec<Vec<T>>) -> Self {
+        match Board::try_new(rows) {
+            Ok(board) => board,
+            Err(e) => panic!("{}", e),
+        }
+    }
 
+    /// Board of the given size with every cell set to `value`.
+    pub fn filled(n_rows: usize, n_cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
         Board {
-            board,
+            cells: vec![value; n_rows * n_cols],
             n_rows,
             n_cols,
         }
     }
 
-    pub fn get_value(&self, row: usize, col: usize) -> Result<&T> {
+    pub fn try_new(rows: Vec<Vec<T>>) -> Result<Self, BoardError> {
+        let n_rows = rows.len();
+        let n_cols = rows.first().map_or(0, Vec::len);
+
+        let mut cells = Vec::with_capacity(n_rows * n_cols);
+        for (row_index, row) in rows.into_iter().enumerate() {
+            if row.len() != n_cols {
+                return Err(BoardError::RaggedRow {
+                    row: row_index,
+                    len: row.len(),
+                    n_cols,
+                });
+            }
+            cells.extend(row);
+        }
+
+        Ok(Board {
+            cells,
+            n_rows,
+            n_cols,
+        })
+    }
+
+    /// Value at the point, or `None` when the point is outside of the board.
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        let index = self.index_of(point)?;
+        Some(&self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        let index = self.index_of(point)?;
+        Some(&mut self.cells[index])
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get_value(&self, row: usize, col: usize) -> Result<&T, BoardError> {
         if row < self.n_rows && col < self.n_cols {
-            return Ok(&self.board[row][col]);
+            return Ok(&self.cells[row * self.n_cols + col]);
         }
-        Err(Error::msg("Attempting to get out of bounds value"))
+        Err(self.out_of_bounds(Point::from_row_col(row, col)))
     }
 
-    pub fn get_value_from_point(&self, point: &Point) -> Result<&T> {
-        self.get_value(point.y as usize, point.x as usize)
+    pub fn get_value_from_point(&self, point: &Point) -> Result<&T, BoardError> {
+        self.get(point).ok_or_else(|| self.out_of_bounds(*point))
     }
 
-    pub fn set_value(&mut self, row: usize, col: usize, value: T) -> Result<()> {
+    pub fn set_value(&mut self, row: usize, col: usize, value: T) -> Result<(), BoardError> {
         if row < self.n_rows && col < self.n_cols {
-            self.board[row][col] = value;
+            self.cells[row * self.n_cols + col] = value;
             return Ok(());
         }
-        Err(Error::msg("Attempting to set out of bounds value"))
+        Err(self.out_of_bounds(Point::from_row_col(row, col)))
+    }
+
+    pub fn set_value_from_point(&mut self, point: &Point, value: T) -> Result<(), BoardError> {
+        match self.index_of(point) {
+            Some(index) => {
+                self.cells[index] = value;
+                Ok(())
+            }
+            None => Err(self.out_of_bounds(*point)),
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(
+            row < self.n_rows,
+            "Row {} is out of bounds of the {}x{} board",
+            row,
+            self.n_rows,
+            self.n_cols
+        );
+        &self.cells[row * self.n_cols..(row + 1) * self.n_cols]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` valid for a board without columns
+        self.cells.chunks(self.n_cols.max(1))
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            col < self.n_cols,
+            "Column {} is out of bounds of the {}x{} board",
+            col,
+            self.n_rows,
+            self.n_cols
+        );
+        self.cells.iter().skip(col).step_by(self.n_cols)
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.n_cols).map(|col| self.col(col))
+    }
+
+    /// Borrowed rectangle of the board with `top_left` as its first cell.
+    pub fn view(
+        &self,
+        top_left: Point,
+        n_rows: usize,
+        n_cols: usize,
+    ) -> Result<BoardView<'_, T>, BoardError> {
+        let (row, col) = top_left
+            .row_col()
+            .ok_or_else(|| self.out_of_bounds(top_left))?;
+        if row + n_rows > self.n_rows || col + n_cols > self.n_cols {
+            let bottom_right = top_left + Point::from_row_col(n_rows, n_cols) - Point::new(1, 1);
+            return Err(self.out_of_bounds(bottom_right));
+        }
+        Ok(BoardView {
+            board: self,
+            row,
+            col,
+            n_rows,
+            n_cols,
+        })
+    }
+
+    /// Up to 4 neighbours of the point (left, right, top, bottom) which are on the board.
+    pub fn neighbors4<'a>(&'a self, point: &Point) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        self.neighbors_on_board(point, &Move::CARDINALS)
+    }
+
+    /// Up to 8 neighbours of the point, including the diagonal ones, which are on the board.
+    pub fn neighbors8<'a>(&'a self, point: &Point) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        self.neighbors_on_board(point, &Move::ALL)
+    }
+
+    /// All 4 neighbours of the point, with `None` for the ones outside of the board,
+    /// e.g. to count the edges of a region touching the border.
+    pub fn neighbors4_or_outside<'a>(
+        &'a self,
+        point: &Point,
+    ) -> impl Iterator<Item = (Point, Option<&'a T>)> + 'a {
+        let point = *point;
+        Move::CARDINALS.iter().map(move |direction| {
+            let neighbor = point + direction.coordinates();
+            let value = self.get(&neighbor);
+            (neighbor, value)
+        })
+    }
+
+    fn neighbors_on_board<'a>(
+        &'a self,
+        point: &Point,
+        directions: &'static [Move],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        let point = *point;
+        directions.iter().filter_map(move |direction| {
+            let neighbor = point + direction.coordinates();
+            let value = self.get(&neighbor)?;
+            Some((neighbor, value))
+        })
+    }
+
+    /// Move the point onto the board as if its opposite edges were connected.
+    /// Panics when the board has no rows or no columns.
+    pub fn wrap(&self, point: Point) -> Point {
+        assert!(
+            self.n_rows > 0 && self.n_cols > 0,
+            "Can't wrap a point onto the empty {}x{} board",
+            self.n_rows,
+            self.n_cols
+        );
+        Point {
+            x: point.x.rem_euclid(self.n_cols as i32),
+            y: point.y.rem_euclid(self.n_rows as i32),
+        }
     }
 
-    pub fn set_value_from_point(&mut self, point: &Point, value: T) -> Result<()> {
-        let row_index = point.y as usize;
-        let col_index = point.x as usize;
-        self.set_value(row_index, col_index, value)
+    /// Index of the point in `cells`, if it's on the board.
+    fn index_of(&self, point: &Point) -> Option<usize> {
+        let (row, col) = point.row_col()?;
+        (row < self.n_rows && col < self.n_cols).then_some(row * self.n_cols + col)
+    }
+
+    fn out_of_bounds(&self, point: Point) -> BoardError {
+        BoardError::OutOfBounds {
+            point,
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+        }
     }
 
     pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
-        self.board
+        let n_cols = self.n_cols;
+        self.cells
             .iter()
-            .enumerate() // (row_index, &row)
-            .flat_map(|(row_index, row)| {
-                row.iter()
-                    .enumerate() // (col_index, &value)
-                    .map(move |(col_index, value)| {
-                        (
-                            Point {
-                                x: col_index as i32,
-                                y: row_index as i32,
-                            },
-                            value,
-                        )
-                    })
-            })
+            .enumerate()
+            .map(move |(index, value)| (Point::from_row_col(index / n_cols, index % n_cols), value))
     }
 }
 
-impl Board<char> {
-    pub fn from_buffer<R: BufRead>(reader: R) -> Self {
-        let mut rows: Vec<Vec<char>> = Vec::new();
-        for line in reader.lines().flatten() {
-            let trimmed = line.trim();
-            if !trimmed.is_empty() {
-                rows.push(trimmed.chars().collect());
-            }
+impl<T: Clone> Board<T> {
+    /// Board of the given size with the value of every cell computed from its row and column.
+    pub fn from_fn(n_rows: usize, n_cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..n_rows * n_cols)
+            .map(|index| f(index / n_cols, index % n_cols))
+            .collect();
+        Board {
+            cells,
+            n_rows,
+            n_cols,
         }
-        Board::new(rows)
     }
 
-    pub fn print(&self) {
-        for row in &self.board {
-            println!("{}", row.iter().collect::<String>());
+    /// Rows become columns, the cell at (row, col) moves to (col, row).
+    pub fn transpose(&self) -> Self {
+        Board::from_fn(self.n_cols, self.n_rows, |row, col| self.cell(col, row))
+    }
+
+    /// The board turned by 90 degrees clockwise.
+    pub fn rotate90(&self) -> Self {
+        Board::from_fn(self.n_cols, self.n_rows, |row, col| {
+            self.cell(self.n_rows - 1 - col, row)
+        })
+    }
+
+    /// The board mirrored left to right.
+    pub fn flip_h(&self) -> Self {
+        Board::from_fn(self.n_rows, self.n_cols, |row, col| {
+            self.cell(row, self.n_cols - 1 - col)
+        })
+    }
+
+    /// The board mirrored upside down.
+    pub fn flip_v(&self) -> Self {
+        Board::from_fn(self.n_rows, self.n_cols, |row, col| {
+            self.cell(self.n_rows - 1 - row, col)
+        })
+    }
+
+    fn cell(&self, row: usize, col: usize) -> T {
+        self.cells[row * self.n_cols + col].clone()
+    }
+}
+
+impl<T> Index<&Point> for Board<T> {
+    type Output = T;
+
+    fn index(&self, point: &Point) -> &T {
+        match self.index_of(point) {
+            Some(index) => &self.cells[index],
+            None => panic!("{}", self.out_of_bounds(*point)),
         }
     }
 }
 
-impl Board<i8> {
-    pub fn from_buffer<R: BufRead>(reader: R) -> Self {
-        let mut rows: Vec<Vec<i8>> = Vec::new();
-        for line in reader.lines().flatten() {
-            let trimmed = line.trim();
-            if !trimmed.is_empty() {
-                rows.push(
-                    trimmed
-                        .chars()
-                        .map(|c| c.to_digit(10).unwrap())
-                        .map(|num| num as i8)
-                        .collect(),
-                );
-            }
+impl<T> IndexMut<&Point> for Board<T> {
+    fn index_mut(&mut self, point: &Point) -> &mut T {
+        match self.index_of(point) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("{}", self.out_of_bounds(*point)),
         }
-        Board::new(rows)
     }
 }
 
+impl<T> Index<Point> for Board<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        &self[&point]
+    }
+}
+
+impl<T> IndexMut<Point> for Board<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        &mut self[&point]
+    }
+}
+
+/// Rectangle of a [`Board`], borrowed without copying the cells.
+///
+/// Points are relative to the top left cell of the view.
 #[derive(Debug)]
+pub struct BoardView<'a, T> {
+    board: &'a Board<T>,
+    row: usize,
+    col: usize,
+    pub n_rows: usize,
+    pub n_cols: usize,
+}
+
+impl<T> Clone for BoardView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BoardView<'_, T> {}
+
+impl<'a, T> BoardView<'a, T> {
+    pub fn get(&self, point: &Point) -> Option<&'a T> {
+        let (row, col) = point.row_col()?;
+        if row < self.n_rows && col < self.n_cols {
+            return self
+                .board
+                .get(&Point::from_row_col(self.row + row, self.col + col));
+        }
+        None
+    }
+
+    pub fn row(&self, row: usize) -> &'a [T] {
+        assert!(
+            row < self.n_rows,
+            "Row {} is out of bounds of the view",
+            row
+        );
+        &self.board.row(self.row + row)[self.col..self.col + self.n_cols]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let view = *self;
+        (0..self.n_rows).map(move |row| view.row(row))
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &'a T> {
+        assert!(
+            col < self.n_cols,
+            "Column {} is out of bounds of the view",
+            col
+        );
+        self.board
+            .col(self.col + col)
+            .skip(self.row)
+            .take(self.n_rows)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &'a T)> {
+        self.rows().enumerate().flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(move |(col, value)| (Point::from_row_col(row, col), value))
+        })
+    }
+
+    /// Copy the view into a board of its own.
+    pub fn to_board(&self) -> Board<T>
+    where
+        T: Clone,
+    {
+        Board::new(self.rows().map(|row| row.to_vec()).collect())
+    }
+}
+
+/// Cell type which can be read from a character of the puzzle input.
+pub trait FromChar: Sized {
+    fn from_char(c: char) -> Option<Self>;
+}
+
+/// Cell type which can be drawn as a single character.
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+
+impl FromChar for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+impl ToChar for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// Single digit, e.g. a height on a topographic map.
+impl FromChar for i8 {
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10).map(|digit| digit as i8)
+    }
+}
+
+impl ToChar for i8 {
+    fn to_char(&self) -> char {
+        u32::try_from(*self)
+            .ok()
+            .and_then(|digit| char::from_digit(digit, 10))
+            .unwrap_or('?')
+    }
+}
+
+impl<T: FromChar> Board<T> {
+    /// Read the board from lines of the input, one character per cell.
+    ///
+    /// Blank lines before the board are skipped and the first blank line after it ends
+    /// the board, so the rest of the input can be read from the same reader.
+    pub fn parse<R: BufRead>(reader: &mut R) -> anyhow::Result<Self> {
+        let mut lines = Vec::new();
+        for (index, text) in reader.lines().enumerate() {
+            let text = text?;
+            if text.trim().is_empty() {
+                if lines.is_empty() {
+                    continue;
+                }
+                break;
+            }
+            lines.push(Line::new(index + 1, &text));
+        }
+        Ok(Board::from_lines(&lines)?)
+    }
+
+    /// Board drawn in a test, panics when it can't be parsed.
+    #[cfg(test)]
+    pub(crate) fn from_text(text: &str) -> Self {
+        Board::parse(&mut text.as_bytes()).unwrap()
+    }
+
+    /// Build the board from already read lines, e.g. a section of the input.
+    pub fn from_lines(lines: &[Line]) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::with_capacity(lines.len());
+        for line in lines {
+            let start = line.text.len() - line.text.trim_start().len();
+            let row = line
+                .text
+                .trim()
+                .char_indices()
+                .map(|(offset, c)| {
+                    T::from_char(c).ok_or_else(|| {
+                        line.error(start + offset, format!("invalid character '{}'", c))
+                    })
+                })
+                .collect::<Result<Vec<T>, ParseError>>()?;
+            if let Some(first_row) = rows.first() {
+                if row.len() != first_row.len() {
+                    return Err(line.error(
+                        start,
+                        format!("expected {} columns, got {}", first_row.len(), row.len()),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Board::new(rows))
+    }
+}
+
+/// The board as text, one line per row. See [`Board::renderer`] for highlights and colours.
+impl<T: ToChar> Display for Board<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            let line: String = row.iter().map(ToChar::to_char).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: ToChar> Board<T> {
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn print(&self) {
+        print!("{}", self);
+    }
+}
+
+/// Direction to one of the 8 neighbouring cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Move {
     Left,
     Right,
@@ -116,6 +551,21 @@ pub enum Move {
 }
 
 impl Move {
+    /// Directions to the 4 neighbours sharing an edge.
+    pub const CARDINALS: [Move; 4] = [Move::Left, Move::Right, Move::Top, Move::Bottom];
+
+    /// Directions to all 8 neighbours.
+    pub const ALL: [Move; 8] = [
+        Move::Left,
+        Move::Right,
+        Move::Top,
+        Move::Bottom,
+        Move::TopLeft,
+        Move::TopRight,
+        Move::BottomLeft,
+        Move::BottomRight,
+    ];
+
     pub fn coordinates(&self) -> Point {
         match self {
             Move::Left => Point { x: -1, y: 0 },
@@ -128,19 +578,188 @@ impl Move {
             Move::BottomRight => Point { x: 1, y: 1 },
         }
     }
+
+    /// The 4 directions to the neighbours sharing an edge.
+    pub fn cardinals() -> impl Iterator<Item = Move> {
+        Move::CARDINALS.into_iter()
+    }
+
+    /// All 8 directions, the cardinal ones first.
+    pub fn all() -> impl Iterator<Item = Move> {
+        Move::ALL.into_iter()
+    }
+
+    /// Turn by 90 degrees to the right, as seen on the screen.
+    pub fn turn_right(self) -> Move {
+        match self {
+            Move::Top => Move::Right,
+            Move::Right => Move::Bottom,
+            Move::Bottom => Move::Left,
+            Move::Left => Move::Top,
+            Move::TopRight => Move::BottomRight,
+            Move::BottomRight => Move::BottomLeft,
+            Move::BottomLeft => Move::TopLeft,
+            Move::TopLeft => Move::TopRight,
+        }
+    }
+
+    /// Turn by 90 degrees to the left, as seen on the screen.
+    pub fn turn_left(self) -> Move {
+        self.opposite().turn_right()
+    }
+
+    pub fn opposite(self) -> Move {
+        self.turn_right().turn_right()
+    }
+
+    /// Direction drawn as an arrow: `^`, `v`, `<` or `>`.
+    pub fn from_arrow(c: char) -> Option<Move> {
+        match c {
+            '^' => Some(Move::Top),
+            'v' => Some(Move::Bottom),
+            '<' => Some(Move::Left),
+            '>' => Some(Move::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Character which doesn't stand for a direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidMove(pub char);
+
+impl Display for InvalidMove {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid direction '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidMove {}
+
+/// Accepts arrows (`^`, `v`, `<`, `>`), `U`, `D`, `L`, `R` and compass points `N`, `E`, `S`, `W`.
+impl TryFrom<char> for Move {
+    type Error = InvalidMove;
+
+    fn try_from(c: char) -> Result<Self, InvalidMove> {
+        if let Some(direction) = Move::from_arrow(c) {
+            return Ok(direction);
+        }
+        match c {
+            'U' | 'N' => Ok(Move::Top),
+            'D' | 'S' => Ok(Move::Bottom),
+            'L' | 'W' => Ok(Move::Left),
+            'R' | 'E' => Ok(Move::Right),
+            _ => Err(InvalidMove(c)),
+        }
+    }
 }
 
-#[derive(Debug, Eq, PartialEq, Hash)]
+/// Position or offset on a board, `x` is the column and `y` the row (growing downwards).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Point {
     pub x: i32,
     pub y: i32,
 }
 
 impl Point {
-    pub fn add(&self, point: &Point) -> Point {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn from_row_col(row: usize, col: usize) -> Self {
+        Point {
+            x: col as i32,
+            y: row as i32,
+        }
+    }
+
+    /// Row and column of the point, `None` when a coordinate is negative.
+    pub fn row_col(self) -> Option<(usize, usize)> {
+        let row = usize::try_from(self.y).ok()?;
+        let col = usize::try_from(self.x).ok()?;
+        Some((row, col))
+    }
+
+    /// Number of steps between the points moving only horizontally and vertically.
+    pub fn manhattan(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Number of steps between the points when diagonal moves are allowed too.
+    pub fn chebyshev(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Rotate by 90 degrees clockwise around the origin, as seen on the screen,
+    /// e.g. the direction up `(0, -1)` becomes right `(1, 0)`.
+    pub fn rotate_cw(self) -> Point {
+        Point {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Rotate by 90 degrees counterclockwise around the origin, as seen on the screen.
+    pub fn rotate_ccw(self) -> Point {
+        Point {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i32) -> Point {
         Point {
-            x: self.x + point.x,
-            y: self.y + point.y,
+            x: self.x * factor,
+            y: self.y * factor,
         }
     }
 }
@@ -149,62 +768,109 @@ fn move_to_direction<'a, T>(
     board: &'a Board<T>,
     current_position: &Point,
     direction: &Point,
-) -> Result<(Point, &'a T)> {
-    let new_position = current_position.add(direction);
-
-    let value = board.get_value_from_point(&new_position);
-
-    match value {
-        Result::Ok(value) => Ok((new_position, &value)),
-        Err(e) => Err(e),
-    }
+) -> Option<(Point, &'a T)> {
+    let new_position = *current_position + *direction;
+    let value = board.get(&new_position)?;
+    Some((new_position, value))
 }
 
-#[derive(Debug)]
-pub struct MoveIterator<'a> {
-    board: &'a Board<char>,
+/// Ray of cells starting at a point and going in one direction, yielding `(Point, &T)`.
+///
+/// The starting cell is yielded first. The ray ends at the edge of the board, unless it
+/// wraps around, after `max_steps` moves or before the first cell matching `stop_at`.
+#[derive(Debug, Clone)]
+pub struct MoveIterator<'a, T, F = fn(&T) -> bool> {
+    board: &'a Board<T>,
     current_position: Point,
     direction: Point,
     first_move: bool,
+    finished: bool,
+    remaining_steps: Option<usize>,
+    wrap: bool,
+    stop: F,
 }
 
-impl<'a> MoveIterator<'a> {
-    pub fn new(board: &'a Board<char>, current_position: &Point, direction: &Move) -> Self {
+impl<'a, T> MoveIterator<'a, T> {
+    pub fn new(board: &'a Board<T>, current_position: &Point, direction: &Move) -> Self {
         MoveIterator {
             board,
-            current_position: Point {
-                x: current_position.x,
-                y: current_position.y,
-            },
+            current_position: *current_position,
             direction: direction.coordinates(),
             first_move: true,
+            finished: false,
+            remaining_steps: None,
+            wrap: false,
+            stop: |_| false,
         }
     }
 }
 
-impl<'a> Iterator for MoveIterator<'a> {
-    type Item = &'a char;
+impl<'a, T, F: FnMut(&T) -> bool> MoveIterator<'a, T, F> {
+    /// Move at most `steps` times, so at most `steps + 1` cells are yielded.
+    pub fn max_steps(mut self, steps: usize) -> Self {
+        self.remaining_steps = Some(steps);
+        self
+    }
+
+    /// End the ray before the first cell matching the predicate, e.g. a wall.
+    pub fn stop_at<G: FnMut(&T) -> bool>(self, stop: G) -> MoveIterator<'a, T, G> {
+        MoveIterator {
+            board: self.board,
+            current_position: self.current_position,
+            direction: self.direction,
+            first_move: self.first_move,
+            finished: self.finished,
+            remaining_steps: self.remaining_steps,
+            wrap: self.wrap,
+            stop,
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Continue from the opposite edge when leaving the board. Without `max_steps` or
+    /// `stop_at` the ray never ends.
+    pub fn wrapping(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    fn step(&mut self) -> Option<(Point, &'a T)> {
         if self.first_move {
-            let result = self.board.get_value_from_point(&self.current_position);
-            match result {
-                Result::Ok(result) => {
-                    self.first_move = false;
-                    return Some(result);
-                }
-                Err(_) => None::<Self::Item>,
-            };
+            self.first_move = false;
+            let value = self.board.get(&self.current_position)?;
+            return Some((self.current_position, value));
+        }
+
+        match self.remaining_steps {
+            Some(0) => return None,
+            Some(steps) => self.remaining_steps = Some(steps - 1),
+            None => {}
+        }
+        if self.wrap {
+            let new_position = self.board.wrap(self.current_position + self.direction);
+            let value = self.board.get(&new_position)?;
+            return Some((new_position, value));
+        }
+        move_to_direction(self.board, &self.current_position, &self.direction)
+    }
+}
+
+impl<'a, T, F: FnMut(&T) -> bool> Iterator for MoveIterator<'a, T, F> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
         }
 
-        let result = move_to_direction(&self.board, &self.current_position, &self.direction);
-        match result {
-            Result::Ok(result) => {
-                let (new_position, value) = result;
+        match self.step() {
+            Some((new_position, value)) if !(self.stop)(value) => {
                 self.current_position = new_position;
-                Some(value)
+                Some((new_position, value))
+            }
+            _ => {
+                self.finished = true;
+                None
             }
-            Err(_) => None,
         }
     }
 }
@@ -256,28 +922,113 @@ mod tests {
         let board = Board::new(vec![row_1, row_2]);
 
         let value = board.get_value(2, 0);
-        assert!(matches!(value, Err(_)));
+        assert!(value.is_err());
+    }
+
+    #[test]
+    fn out_of_bounds_error_has_point_and_board_size() {
+        let board = Board::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let error = board
+            .get_value_from_point(&Point { x: -1, y: 1 })
+            .unwrap_err();
+        assert_eq!(
+            error,
+            BoardError::OutOfBounds {
+                point: Point { x: -1, y: 1 },
+                n_rows: 2,
+                n_cols: 3
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Point (x: -1, y: 1) is out of bounds of the 2x3 board"
+        );
+    }
+
+    #[test]
+    fn get_returns_none_outside_of_board() {
+        let mut board = Board::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(board.get(&Point { x: 2, y: 1 }), Some(&6));
+        assert_eq!(board.get(&Point { x: 3, y: 0 }), None);
+        assert_eq!(board.get(&Point { x: 0, y: -1 }), None);
+        assert!(!board.contains(&Point { x: -1, y: 0 }));
+
+        *board.get_mut(&Point { x: 0, y: 0 }).unwrap() = 7;
+        assert_eq!(*board.get_value(0, 0).unwrap(), 7);
     }
 
     #[test]
-    fn create_board_from_buffer() {
+    fn index_with_point() {
+        let mut board = Board::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(board[Point { x: 1, y: 1 }], 5);
+        board[&Point { x: 0, y: 1 }] = 8;
+        assert_eq!(board.row(1), &[8, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Point (x: 0, y: -1) is out of bounds of the 2x3 board")]
+    fn index_with_negative_point_panics() {
+        let board = Board::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let _ = board[Point { x: 0, y: -1 }];
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 2 is out of bounds of the 2x3 board")]
+    fn row_out_of_bounds_panics() {
+        let board = Board::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let _ = board.row(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't wrap a point onto the empty 0x3 board")]
+    fn wrap_on_empty_board_panics() {
+        let board: Board<i32> = Board::filled(0, 3, 0);
+        board.wrap(Point::new(1, 1));
+    }
+
+    #[test]
+    fn reject_ragged_rows() {
+        let error = Board::try_new(vec![vec![1, 2, 3], vec![4, 5]]).unwrap_err();
+        assert_eq!(
+            error,
+            BoardError::RaggedRow {
+                row: 1,
+                len: 2,
+                n_cols: 3
+            }
+        );
+    }
+
+    #[test]
+    fn iterate_over_rows() {
+        let board = Board::new(vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+
+        let rows: Vec<&[i32]> = board.rows().collect();
+        assert_eq!(rows, vec![&[1, 2], &[3, 4], &[5, 6]]);
+    }
+
+    #[test]
+    fn parse_board() {
         let input_buffer: &str = "\
             ABCDE
             EFGHI";
 
-        let board: Board<char> = Board::<char>::from_buffer(input_buffer.as_bytes());
+        let board: Board<char> = Board::from_text(input_buffer);
         assert_eq!(board.n_rows, 2);
         assert_eq!(board.n_cols, 5);
     }
 
     #[test]
-    fn board_from_buffer_doesnt_read_empty_lines() {
+    fn parse_board_stops_at_empty_line() {
         let input_buffer: &str = "\
             ABCDE
 
         ";
 
-        let board: Board<char> = Board::<char>::from_buffer(input_buffer.as_bytes());
+        let board: Board<char> = Board::from_text(input_buffer);
         assert_eq!(board.n_rows, 1);
     }
 
@@ -293,6 +1044,213 @@ mod tests {
         assert_eq!(*value, 6);
     }
 
+    #[test]
+    fn parse_board_and_leave_next_section_in_reader() {
+        let mut input = "\n12\n34\n\nrest\n".as_bytes();
+
+        let board: Board<i8> = Board::parse(&mut input).unwrap();
+        assert_eq!(board.row(1), &[3, 4]);
+        assert_eq!(input, b"rest\n");
+    }
+
+    #[test]
+    fn report_invalid_character_with_position() {
+        let error = Board::<i8>::parse(&mut "12\n3x\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: invalid character 'x'");
+    }
+
+    #[test]
+    fn report_row_of_different_length() {
+        let error = Board::<char>::parse(&mut "ab\nabc\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected 2 columns, got 3"
+        );
+    }
+
+    #[test]
+    fn render_board() {
+        let board = Board::new(vec![vec![1_i8, 2], vec![3, 4]]);
+        assert_eq!(board.render(), "12\n34\n");
+    }
+
+    #[test]
+    fn neighbors_on_board_only() {
+        let board = Board::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let mut neighbors: Vec<i32> = board
+            .neighbors4(&Point { x: 0, y: 0 })
+            .map(|(_, value)| *value)
+            .collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec![2, 4]);
+
+        let mut neighbors: Vec<i32> = board
+            .neighbors8(&Point { x: 1, y: 1 })
+            .map(|(_, value)| *value)
+            .collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec![1, 2, 3, 4, 6]);
+    }
+
+    #[test]
+    fn neighbors_including_outside_of_board() {
+        let board = Board::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let neighbors: Vec<(Point, Option<&i32>)> =
+            board.neighbors4_or_outside(&Point { x: 0, y: 0 }).collect();
+        assert_eq!(neighbors.len(), 4);
+        assert!(neighbors.contains(&(Point { x: -1, y: 0 }, None)));
+        assert!(neighbors.contains(&(Point { x: 1, y: 0 }, Some(&2))));
+        assert_eq!(
+            neighbors
+                .iter()
+                .filter(|(_, value)| value.is_none())
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn point_arithmetic() {
+        let a = Point::new(2, -3);
+        let b = Point::new(1, 4);
+
+        assert_eq!(a + b, Point::new(3, 1));
+        assert_eq!(a - b, Point::new(1, -7));
+        assert_eq!(-a, Point::new(-2, 3));
+        assert_eq!(b * 3, Point::new(3, 12));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn point_distances() {
+        let a = Point::new(2, -3);
+        let b = Point::new(-1, 1);
+
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+    }
+
+    #[test]
+    fn rotate_point() {
+        let up = Move::Top.coordinates();
+
+        assert_eq!(up.rotate_cw(), Move::Right.coordinates());
+        assert_eq!(up.rotate_ccw(), Move::Left.coordinates());
+        assert_eq!(up.rotate_cw().rotate_cw(), -up);
+        assert_eq!(up.rotate_cw().rotate_ccw(), up);
+    }
+
+    #[test]
+    fn point_to_and_from_row_and_column() {
+        assert_eq!(Point::from_row_col(2, 5), Point::new(5, 2));
+        assert_eq!(Point::new(5, 2).row_col(), Some((2, 5)));
+        assert_eq!(Point::new(-1, 2).row_col(), None);
+    }
+
+    #[test]
+    fn turn_and_reverse_direction() {
+        assert_eq!(Move::Top.turn_right(), Move::Right);
+        assert_eq!(Move::Top.turn_left(), Move::Left);
+        assert_eq!(Move::TopLeft.turn_right(), Move::TopRight);
+        assert_eq!(Move::BottomLeft.opposite(), Move::TopRight);
+        for direction in Move::all() {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.opposite().coordinates(), -direction.coordinates());
+            assert_eq!(
+                direction.turn_right().coordinates(),
+                direction.coordinates().rotate_cw()
+            );
+        }
+    }
+
+    #[test]
+    fn parse_direction_from_char() {
+        assert_eq!(Move::try_from('^'), Ok(Move::Top));
+        assert_eq!(Move::try_from('>'), Ok(Move::Right));
+        assert_eq!(Move::try_from('D'), Ok(Move::Bottom));
+        assert_eq!(Move::try_from('W'), Ok(Move::Left));
+        assert_eq!(Move::try_from('x'), Err(InvalidMove('x')));
+        assert_eq!(Move::from_arrow('N'), None);
+    }
+
+    #[test]
+    fn iterate_over_directions() {
+        assert_eq!(Move::cardinals().count(), 4);
+        assert_eq!(Move::all().count(), 8);
+        assert!(Move::cardinals()
+            .all(|direction| direction.coordinates().manhattan(Point::default()) == 1));
+    }
+
+    fn board_3x2() -> Board<i32> {
+        Board::new(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn transform_board() {
+        let board = board_3x2();
+
+        assert_eq!(
+            board.transpose(),
+            Board::new(vec![vec![1, 4], vec![2, 5], vec![3, 6]])
+        );
+        assert_eq!(
+            board.rotate90(),
+            Board::new(vec![vec![4, 1], vec![5, 2], vec![6, 3]])
+        );
+        assert_eq!(
+            board.flip_h(),
+            Board::new(vec![vec![3, 2, 1], vec![6, 5, 4]])
+        );
+        assert_eq!(
+            board.flip_v(),
+            Board::new(vec![vec![4, 5, 6], vec![1, 2, 3]])
+        );
+        assert_eq!(board.rotate90().rotate90(), board.flip_h().flip_v());
+    }
+
+    #[test]
+    fn iterate_over_columns() {
+        let board = board_3x2();
+
+        assert_eq!(board.col(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        let sums: Vec<i32> = board.cols().map(|col| col.sum()).collect();
+        assert_eq!(sums, vec![5, 7, 9]);
+    }
+
+    #[test]
+    fn view_of_board() {
+        let board = Board::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let view = board.view(Point::new(1, 1), 2, 2).unwrap();
+
+        assert_eq!(view.get(&Point::new(0, 0)), Some(&5));
+        assert_eq!(view.get(&Point::new(2, 0)), None);
+        assert_eq!(view.row(1), &[8, 9]);
+        assert_eq!(view.col(1).copied().collect::<Vec<_>>(), vec![6, 9]);
+        assert_eq!(view.iter().count(), 4);
+        assert_eq!(view.to_board(), Board::new(vec![vec![5, 6], vec![8, 9]]));
+    }
+
+    #[test]
+    fn view_outside_of_board() {
+        let board = board_3x2();
+
+        assert_eq!(
+            board.view(Point::new(1, 0), 2, 3).unwrap_err(),
+            BoardError::OutOfBounds {
+                point: Point::new(3, 1),
+                n_rows: 2,
+                n_cols: 3
+            }
+        );
+        assert!(board.view(Point::new(-1, 0), 1, 1).is_err());
+    }
+
     // Tests of moving to direction
     #[test]
     fn move_one_step_bottom_right() {
@@ -324,10 +1282,10 @@ mod tests {
         let mut move_iterator = MoveIterator::new(&board, &starting_position, &direction);
 
         let a = move_iterator.next().unwrap();
-        assert_eq!(*a, 'a');
+        assert_eq!(a, (Point::new(0, 0), &'a'));
 
         let b = move_iterator.next().unwrap();
-        assert_eq!(*b, 'b');
+        assert_eq!(b, (Point::new(1, 0), &'b'));
     }
 
     #[test]
@@ -341,9 +1299,43 @@ mod tests {
         let move_iterator = MoveIterator::new(&board, &starting_position, &direction);
 
         let mut last_char = 'a';
-        for x in move_iterator {
+        for (_, x) in move_iterator {
             last_char = *x;
         }
         assert_eq!(last_char, 'b');
     }
+
+    #[test]
+    fn iterate_limited_number_of_steps() {
+        let board = Board::new(vec!["abcde".chars().collect()]);
+
+        let letters: String = MoveIterator::new(&board, &Point::new(1, 0), &Move::Right)
+            .max_steps(2)
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!(letters, "bcd");
+    }
+
+    #[test]
+    fn iterate_until_stop() {
+        let board = Board::new(vec!["ab#cd".chars().collect()]);
+
+        let points: Vec<Point> = MoveIterator::new(&board, &Point::new(0, 0), &Move::Right)
+            .stop_at(|c| *c == '#')
+            .map(|(point, _)| point)
+            .collect();
+        assert_eq!(points, vec![Point::new(0, 0), Point::new(1, 0)]);
+    }
+
+    #[test]
+    fn iterate_with_wrapping() {
+        let board = Board::new(vec![vec![1, 2, 3]]);
+
+        let values: Vec<i32> = MoveIterator::new(&board, &Point::new(1, 0), &Move::Left)
+            .wrapping()
+            .max_steps(4)
+            .map(|(_, value)| *value)
+            .collect();
+        assert_eq!(values, vec![2, 1, 3, 2, 1]);
+    }
 }
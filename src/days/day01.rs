@@ -0,0 +1,81 @@
+use crate::parse;
+use crate::solution::Solution;
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: &'static str = "01";
+
+    type Input = (Vec<u32>, Vec<u32>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse<R: BufRead>(reader: &mut R) -> Result<Self::Input> {
+        let [left_list, right_list] = parse::columns(&parse::read_lines(reader)?)?;
+        Ok((left_list, right_list))
+    }
+
+    fn solve_part1(input: &Self::Input) -> Result<u32> {
+        let (mut left_list, mut right_list) = input.clone();
+
+        left_list.sort();
+        right_list.sort();
+
+        let mut difference: u32 = 0;
+        for (x, y) in left_list.iter().zip(right_list) {
+            difference += x.abs_diff(y)
+        }
+        Ok(difference)
+    }
+
+    fn solve_part2(input: &Self::Input) -> Result<u32> {
+        let (left_list, right_list) = input;
+
+        let right_items_counts = count_items(right_list);
+
+        let mut similarity_score: u32 = 0;
+        for left_item in left_list {
+            let count = right_items_counts.get(left_item).unwrap_or(&0);
+            similarity_score += count * left_item;
+        }
+
+        Ok(similarity_score)
+    }
+}
+
+fn count_items(vec: &[u32]) -> HashMap<u32, u32> {
+    let mut counter: HashMap<u32, u32> = HashMap::new();
+    for item in vec {
+        let count = counter.entry(*item).or_insert(0);
+        *count += 1;
+    }
+    counter
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve_example;
+
+    const TEST: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(11, solve_example(Day01::part1, TEST));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(31, solve_example(Day01::part2, TEST));
+    }
+}
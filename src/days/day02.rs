@@ -0,0 +1,105 @@
+use crate::parse;
+use crate::solution::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: &'static str = "02";
+
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: &mut R) -> Result<Self::Input> {
+        Ok(parse::values_per_line(&parse::read_lines(reader)?)?)
+    }
+
+    fn solve_part1(reports: &Self::Input) -> Result<usize> {
+        let mut safe_lines_count = 0;
+        for levels in reports {
+            if are_all_levels_safe(levels.clone()) {
+                safe_lines_count += 1;
+            }
+        }
+        Ok(safe_lines_count)
+    }
+
+    fn solve_part2(reports: &Self::Input) -> Result<usize> {
+        // Brute force solution
+        let mut safe_lines_count = 0;
+        for levels in reports {
+            for index_to_drop in 0..levels.len() {
+                let mut levels_without_one_index = levels.clone();
+                levels_without_one_index.remove(index_to_drop);
+
+                if are_all_levels_safe(levels_without_one_index) {
+                    safe_lines_count += 1;
+                    break;
+                }
+            }
+        }
+        Ok(safe_lines_count)
+    }
+}
+
+fn are_all_levels_safe(levels: Vec<i32>) -> bool {
+    if adjacent_levels_have_incorrect_difference(&levels) {
+        return false;
+    }
+    if !levels_are_monotonic(&levels) {
+        return false;
+    }
+    true
+}
+
+fn adjacent_levels_have_incorrect_difference(levels: &[i32]) -> bool {
+    let min_difference = 1;
+    let max_difference = 3;
+
+    for window in levels.windows(2) {
+        if let [x, y] = window {
+            let difference = (x - y).abs();
+            if difference < min_difference || difference > max_difference {
+                return true;
+            }
+        };
+    }
+    false
+}
+
+fn levels_are_monotonic(levels: &[i32]) -> bool {
+    if levels.is_sorted() {
+        return true;
+    }
+    if levels.iter().rev().is_sorted() {
+        return true;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve_example;
+
+    const TEST: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(2, solve_example(Day02::part1, TEST));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(4, solve_example(Day02::part2, TEST));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::board_matrix::{Board, Move};
+use crate::pattern::{find_templates, find_word, Template};
+use crate::solution::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: &'static str = "04";
+
+    type Input = Board<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: &mut R) -> Result<Board<char>> {
+        Board::parse(reader)
+    }
+
+    fn solve_part1(text_matrix: &Board<char>) -> Result<usize> {
+        let word: Vec<char> = "XMAS".chars().collect();
+
+        Ok(find_word(text_matrix, &word, Move::all()).len())
+    }
+
+    fn solve_part2(text_matrix: &Board<char>) -> Result<usize> {
+        // Two MAS in the shape of an X, in any orientation
+        let cross = Template::parse("M.S\n.A.\nM.S", '.')?;
+
+        Ok(find_templates(text_matrix, &cross.variants()).len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve_example;
+
+    const TEST: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(18, solve_example(Day04::part1, TEST));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(9, solve_example(Day04::part2, TEST));
+    }
+}
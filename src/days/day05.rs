@@ -0,0 +1,188 @@
+use crate::parse::{self, Line};
+use crate::solution::Solution;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+pub struct Day05;
+
+/// Page ordering rules followed by the pages of each update.
+pub struct PrintQueue {
+    rules: RuleSet,
+    pages: Vec<Vec<i32>>,
+}
+
+impl Solution for Day05 {
+    const DAY: &'static str = "05";
+
+    type Input = PrintQueue;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse<R: BufRead>(reader: &mut R) -> Result<PrintQueue> {
+        let sections = parse::read_sections(reader)?;
+        let [rules_section, pages_section] = sections.as_slice() else {
+            return Err(anyhow!(
+                "Expected the rules and the pages separated by a blank line, got {} sections",
+                sections.len()
+            ));
+        };
+        let rules = read_rules(rules_section)?;
+
+        let pages = parse::fields_per_line(pages_section, ',')?;
+
+        Ok(PrintQueue { rules, pages })
+    }
+
+    fn solve_part1(print_queue: &PrintQueue) -> Result<i32> {
+        let PrintQueue { rules, pages } = print_queue;
+
+        let mut answer = 0;
+        for page in pages {
+            if is_page_correct(page, rules) {
+                answer += get_middle_number(page);
+            }
+        }
+        Ok(answer)
+    }
+
+    fn solve_part2(print_queue: &PrintQueue) -> Result<i32> {
+        // The idea of solution is to sort the pages based on a custom ordering
+
+        let PrintQueue { rules, pages } = print_queue;
+
+        let mut answer = 0;
+
+        for page in pages {
+            let sorted_page: Vec<_> = page
+                .clone()
+                .into_iter()
+                .sorted_by(|a, b| {
+                    if rules.is_ordered_pair(*a, *b) {
+                        return std::cmp::Ordering::Less;
+                    }
+                    std::cmp::Ordering::Greater
+                })
+                .collect();
+
+            if *page != sorted_page {
+                answer += get_middle_number(&sorted_page)
+            }
+        }
+
+        Ok(answer)
+    }
+}
+
+fn read_rules(lines: &[Line]) -> Result<RuleSet> {
+    let mut rule_set = RuleSet::new();
+
+    for line in lines {
+        let [left, right] = line.fields_exact('|')?;
+        rule_set.insert(Rule::new(left, right));
+    }
+    Ok(rule_set)
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+struct Rule {
+    left: i32,
+    right: i32,
+}
+
+impl Rule {
+    fn new(left: i32, right: i32) -> Rule {
+        Rule { left, right }
+    }
+}
+
+struct RuleSet {
+    rules: HashSet<Rule>,
+}
+
+impl RuleSet {
+    fn new() -> RuleSet {
+        RuleSet {
+            rules: HashSet::new(),
+        }
+    }
+
+    fn insert(&mut self, rule: Rule) {
+        self.rules.insert(rule);
+    }
+
+    fn contains(&self, rule: &Rule) -> bool {
+        self.rules.contains(rule)
+    }
+
+    fn is_ordered_pair(&self, a: i32, b: i32) -> bool {
+        if self.contains(&Rule::new(a, b)) {
+            return true;
+        }
+        false
+    }
+}
+
+fn is_page_correct(page: &[i32], rules: &RuleSet) -> bool {
+    let mut prev_number = &page[0];
+    for next_number in &page[1..] {
+        if !rules.is_ordered_pair(*prev_number, *next_number) {
+            return false;
+        }
+        prev_number = next_number;
+    }
+    true
+}
+
+fn get_middle_number(page: &[i32]) -> i32 {
+    let middle_index = page.len() / 2;
+    page[middle_index]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve_example;
+
+    const TEST: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(143, solve_example(Day05::part1, TEST));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(123, solve_example(Day05::part2, TEST));
+    }
+}
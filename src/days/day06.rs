@@ -0,0 +1,195 @@
+use crate::board_matrix::{Board, Move, Point};
+use crate::search::Pose;
+use crate::simulation::{Outcome, Simulation};
+use crate::solution::Solution;
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: &'static str = "06";
+
+    type Input = Board<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: &mut R) -> Result<Board<char>> {
+        Board::parse(reader)
+    }
+
+    fn solve_part1(board: &Board<char>) -> Result<usize> {
+        let walk = GuardWalk::new(board)?;
+        Ok(visited_positions(&walk)?.len())
+    }
+
+    fn solve_part2(board: &Board<char>) -> Result<usize> {
+        let n_threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        count_trapping_obstructions(board, n_threads)
+    }
+}
+
+/// Walk of the guard, who goes straight ahead and turns right in front of an obstruction,
+/// until leaving the map.
+pub struct GuardWalk<'a> {
+    map: &'a Board<char>,
+    start: Pose,
+    /// Additional obstruction placed on the map, without modifying it.
+    obstruction: Option<Point>,
+}
+
+impl<'a> GuardWalk<'a> {
+    pub fn new(map: &'a Board<char>) -> Result<Self> {
+        let (position, direction) = find_starting_position_and_direction(map)?;
+        Ok(GuardWalk {
+            map,
+            start: Pose {
+                position,
+                direction,
+            },
+            obstruction: None,
+        })
+    }
+
+    pub fn with_obstruction(&self, obstruction: Point) -> Self {
+        GuardWalk {
+            map: self.map,
+            start: self.start,
+            obstruction: Some(obstruction),
+        }
+    }
+
+    fn is_obstructed(&self, point: &Point) -> bool {
+        self.obstruction == Some(*point) || self.map.get(point) == Some(&'#')
+    }
+}
+
+impl Simulation for GuardWalk<'_> {
+    type State = Pose;
+
+    fn initial_state(&self) -> Pose {
+        self.start
+    }
+
+    fn step(&self, guard: &Pose) -> Pose {
+        let ahead = guard.position + guard.direction.coordinates();
+        if self.is_obstructed(&ahead) {
+            Pose {
+                position: guard.position,
+                direction: guard.direction.turn_right(),
+            }
+        } else {
+            Pose {
+                position: ahead,
+                direction: guard.direction,
+            }
+        }
+    }
+
+    /// The guard is about to leave the map.
+    fn is_terminal(&self, guard: &Pose) -> bool {
+        !self
+            .map
+            .contains(&(guard.position + guard.direction.coordinates()))
+    }
+}
+
+/// Distinct positions of the guard until leaving the map.
+fn visited_positions(walk: &GuardWalk) -> Result<HashSet<Point>> {
+    let mut poses = HashSet::new();
+    for guard in walk.states() {
+        if !poses.insert(guard) {
+            return Err(anyhow!(
+                "The guard walks in a loop and never leaves the map"
+            ));
+        }
+    }
+    Ok(poses.into_iter().map(|guard| guard.position).collect())
+}
+
+/// Number of positions where a new obstruction makes the guard walk in a loop.
+///
+/// Only the positions on the original path matter, the guard never meets an obstruction
+/// placed anywhere else. They are checked on `n_threads` threads.
+pub fn count_trapping_obstructions(board: &Board<char>, n_threads: usize) -> Result<usize> {
+    let walk = GuardWalk::new(board)?;
+    let candidates: Vec<Point> = visited_positions(&walk)?
+        .into_iter()
+        .filter(|point| *point != walk.start.position)
+        .collect();
+
+    let is_trap = |point: &Point| matches!(walk.with_obstruction(*point).run(), Outcome::Cycle(_));
+    let chunk_size = candidates.len().div_ceil(n_threads.max(1)).max(1);
+    let count = std::thread::scope(|scope| {
+        let workers: Vec<_> = candidates
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(|| chunk.iter().filter(|point| is_trap(point)).count()))
+            .collect();
+        workers
+            .into_iter()
+            .map(|worker| worker.join().expect("A worker thread panicked"))
+            .sum()
+    });
+    Ok(count)
+}
+
+fn find_starting_position_and_direction(board: &Board<char>) -> Result<(Point, Move)> {
+    for (position, value) in board.iter() {
+        if let Some(direction) = Move::from_arrow(*value) {
+            return Ok((position, direction));
+        }
+    }
+    Err(anyhow!("No starting position found!"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve_example;
+
+    const TEST: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(41, solve_example(Day06::part1, TEST));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(6, solve_example(Day06::part2, TEST));
+    }
+
+    #[test]
+    fn part2_on_one_or_more_threads() {
+        let board = Day06::parse(&mut TEST.as_bytes()).unwrap();
+        let sequential = count_trapping_obstructions(&board, 1).unwrap();
+        assert_eq!(6, sequential);
+        for n_threads in [2, 4, 64] {
+            assert_eq!(
+                sequential,
+                count_trapping_obstructions(&board, n_threads).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn guard_in_a_loop_never_leaves() {
+        let board = Day06::parse(&mut ".#..\n...#\n#^..\n..#.\n".as_bytes()).unwrap();
+        let walk = GuardWalk::new(&board).unwrap();
+
+        assert!(matches!(walk.run(), Outcome::Cycle(_)));
+        assert!(Day06::solve_part1(&board).is_err());
+    }
+}
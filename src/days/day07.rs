@@ -0,0 +1,108 @@
+use crate::number_utils::concatenate_numbers;
+use crate::parse;
+use crate::solution::Solution;
+use anyhow::*;
+use itertools::Itertools;
+use std::io::BufRead;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: &'static str = "07";
+
+    type Input = Vec<(u64, Vec<u64>)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<R: BufRead>(reader: &mut R) -> Result<Self::Input> {
+        Ok(parse::key_values_per_line(
+            &parse::read_lines(reader)?,
+            ':',
+        )?)
+    }
+
+    fn solve_part1(equations: &Self::Input) -> Result<u64> {
+        let mut answer = 0;
+        let possible_operations = vec![Operation::Add, Operation::Multiply];
+        for (test_value, numbers) in equations {
+            if is_equation_true(*test_value, numbers, &possible_operations) {
+                answer += test_value;
+            }
+        }
+        Ok(answer)
+    }
+
+    fn solve_part2(equations: &Self::Input) -> Result<u64> {
+        let mut answer = 0;
+        let possible_operations = vec![Operation::Add, Operation::Multiply, Operation::Concatenate];
+        for (test_value, numbers) in equations {
+            if is_equation_true(*test_value, numbers, &possible_operations) {
+                answer += test_value;
+            }
+        }
+        Ok(answer)
+    }
+}
+
+fn is_equation_true(test_value: u64, numbers: &[u64], possible_operations: &[Operation]) -> bool {
+    let first_number = numbers[0];
+    let numbers_rest = &numbers[1..];
+
+    let number_of_operations = numbers_rest.len();
+
+    let combos: Vec<_> = (0..number_of_operations)
+        .map(|_| possible_operations.iter().cloned()) // Repeat the iterator r times
+        .multi_cartesian_product()
+        .collect();
+
+    for combo in combos {
+        let mut result = first_number;
+        for (num, operation) in numbers_rest.iter().zip(combo) {
+            match operation {
+                Operation::Add => result += num,
+                Operation::Multiply => result *= num,
+                Operation::Concatenate => result = concatenate_numbers(result, *num),
+            }
+        }
+        if result == test_value {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Add,
+    Multiply,
+    Concatenate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve_example;
+
+    const TEST: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(3749, solve_example(Day07::part1, TEST));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(11387, solve_example(Day07::part2, TEST));
+    }
+}
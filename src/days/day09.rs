@@ -0,0 +1,176 @@
+use crate::parse;
+use crate::solution::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: &'static str = "09";
+
+    type Input = Vec<usize>;
+    type Answer1 = i64;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: &mut R) -> Result<Vec<usize>> {
+        read_disk_map(reader)
+    }
+
+    fn solve_part1(disk_map: &Vec<usize>) -> Result<i64> {
+        let mut blocks: Vec<i32> = Vec::new();
+        let mut free_space_digit = false;
+        let mut block_index = 0;
+
+        for &digit in disk_map {
+            if free_space_digit {
+                blocks.append(&mut vec![-1; digit]);
+            } else {
+                blocks.append(&mut vec![block_index; digit]);
+                block_index += 1;
+            }
+
+            free_space_digit = !free_space_digit;
+        }
+
+        let mut left_index = 0;
+        let mut right_index = blocks.len() - 1;
+        while left_index < right_index {
+            let digit = blocks[left_index];
+            if digit == -1 {
+                let mut digit_to_move = blocks[right_index];
+                while digit_to_move == -1 {
+                    right_index -= 1;
+                    digit_to_move = blocks[right_index]
+                }
+                blocks[left_index] = digit_to_move;
+                blocks[right_index] = 0;
+                right_index -= 1;
+            }
+            left_index += 1;
+        }
+
+        let answer = calculate_hash(&blocks);
+        Ok(answer)
+    }
+
+    fn solve_part2(disk_map: &Vec<usize>) -> Result<usize> {
+        // Divide the dense format representation into file blocks and free space blocks
+
+        let mut file_blocks: Vec<MemoryBlock> = Vec::new();
+        let mut free_space_blocks: Vec<MemoryBlock> = Vec::new();
+        let mut position = 0;
+        let mut file_id = 0;
+        let mut is_file_digit = true;
+
+        for digit in disk_map {
+            if is_file_digit {
+                let file_block = MemoryBlock {
+                    starting_position: position,
+                    id: file_id,
+                    length: *digit,
+                };
+                file_blocks.push(file_block);
+                file_id += 1;
+            } else {
+                let free_space_block = MemoryBlock {
+                    starting_position: position,
+                    id: 0,
+                    length: *digit,
+                };
+                free_space_blocks.push(free_space_block);
+            }
+            position += digit;
+            is_file_digit = !is_file_digit;
+        }
+
+        // Move file blocks starting from right most position
+
+        for file_block in file_blocks.iter_mut().rev() {
+            for free_space_block in free_space_blocks.iter_mut() {
+                if free_space_block.starting_position >= file_block.starting_position {
+                    break;
+                }
+
+                if free_space_block.length >= file_block.length {
+                    file_block.starting_position = free_space_block.starting_position;
+
+                    // There is less free space know
+                    free_space_block.starting_position += file_block.length;
+                    free_space_block.length -= file_block.length;
+                    break;
+                }
+            }
+        }
+
+        Ok(calculate_hash2(&file_blocks))
+    }
+}
+
+fn read_disk_map<R: BufRead>(reader: R) -> Result<Vec<usize>> {
+    let lines = parse::read_lines(reader)?;
+    let first_line = lines.first().ok_or_else(|| anyhow!("The input is empty"))?;
+
+    let mut disk_map = Vec::new();
+    for (offset, c) in first_line.text.trim_end().char_indices() {
+        let digit = c
+            .to_digit(10)
+            .ok_or_else(|| first_line.error(offset, format!("invalid digit '{}'", c)))?;
+        disk_map.push(digit as usize);
+    }
+    Ok(disk_map)
+}
+
+fn calculate_hash(blocks: &[i32]) -> i64 {
+    let mut hash: i64 = 0;
+    for (idx, digit) in blocks.iter().enumerate() {
+        if *digit == -1 {
+            break;
+        }
+        hash += (idx as i64) * (*digit as i64);
+    }
+    hash
+}
+
+fn calculate_hash2(blocks: &[MemoryBlock]) -> usize {
+    let mut hash: usize = 0;
+    for block in blocks {
+        let block_end = block.starting_position + block.length;
+        hash += (block.starting_position + block_end - 1) * block.length * block.id / 2;
+    }
+    hash
+}
+
+#[derive(Debug)]
+struct MemoryBlock {
+    starting_position: usize,
+    id: usize,
+    length: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve_example;
+
+    const TEST: &str = "\
+2333133121414131402
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(1928, solve_example(Day09::part1, TEST));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(2858, solve_example(Day09::part2, TEST));
+    }
+
+    #[test]
+    fn invalid_disk_map_is_an_error() {
+        assert!(Day09::parse(&mut "".as_bytes()).is_err());
+
+        let error = Day09::parse(&mut "12a4\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 3: invalid digit 'a'");
+    }
+}
@@ -0,0 +1,114 @@
+use crate::board_matrix::{Board, Point};
+use crate::solution::Solution;
+use anyhow::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: &'static str = "10";
+
+    type Input = Board<i8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: &mut R) -> Result<Board<i8>> {
+        Board::parse(reader)
+    }
+
+    fn solve_part1(topographic_map: &Board<i8>) -> Result<usize> {
+        let mut counter = 0;
+        for row in 0..topographic_map.n_rows {
+            for col in 0..topographic_map.n_cols {
+                let value = topographic_map.get_value(row, col)?;
+                if *value != 0 {
+                    continue;
+                }
+                let mut unique_trail_ends: HashSet<Point> = HashSet::new();
+                traverse_trail(
+                    Point::from_row_col(row, col),
+                    topographic_map,
+                    &mut unique_trail_ends,
+                );
+                counter += unique_trail_ends.len()
+            }
+        }
+        Ok(counter)
+    }
+
+    fn solve_part2(topographic_map: &Board<i8>) -> Result<usize> {
+        let mut counter = 0;
+        for row in 0..topographic_map.n_rows {
+            for col in 0..topographic_map.n_cols {
+                let value = topographic_map.get_value(row, col)?;
+                if *value != 0 {
+                    continue;
+                }
+                counter +=
+                    traverse_trail_and_count_rating(Point::from_row_col(row, col), topographic_map);
+            }
+        }
+        Ok(counter)
+    }
+}
+
+fn traverse_trail(
+    current_position: Point,
+    topographic_map: &Board<i8>,
+    unique_points: &mut HashSet<Point>,
+) {
+    let current_height = topographic_map[&current_position];
+    if current_height == 9 {
+        unique_points.insert(current_position);
+        return;
+    }
+
+    for (next_position, next_height) in topographic_map.neighbors4(&current_position) {
+        if next_height - current_height == 1 {
+            traverse_trail(next_position, topographic_map, unique_points);
+        }
+    }
+}
+
+fn traverse_trail_and_count_rating(current_position: Point, topographic_map: &Board<i8>) -> usize {
+    let current_height = topographic_map[&current_position];
+    if current_height == 9 {
+        return 1;
+    }
+
+    let mut total = 0;
+    for (next_position, next_height) in topographic_map.neighbors4(&current_position) {
+        if next_height - current_height == 1 {
+            total += traverse_trail_and_count_rating(next_position, topographic_map);
+        }
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve_example;
+
+    const TEST: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(36, solve_example(Day10::part1, TEST));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(81, solve_example(Day10::part2, TEST));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::parse;
+use crate::solution::Solution;
+use anyhow::*;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: &'static str = "11";
+
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<R: BufRead>(reader: &mut R) -> Result<Vec<u64>> {
+        let lines = parse::read_lines(reader)?;
+        let first_line = lines.first().ok_or_else(|| anyhow!("The input is empty"))?;
+        Ok(first_line.values()?)
+    }
+
+    fn solve_part1(stones: &Vec<u64>) -> Result<u64> {
+        let number_of_blinks = 25;
+        let mut stones: Vec<u64> = stones.clone();
+
+        blink_n_times_naive(&mut stones, number_of_blinks);
+        Ok(stones.len().try_into()?)
+    }
+
+    fn solve_part2(stones: &Vec<u64>) -> Result<u64> {
+        let number_of_blinks = 75;
+
+        let answer = recursive_solution(stones, number_of_blinks);
+
+        Ok(answer)
+    }
+}
+
+fn blink_n_times_naive(stones: &mut Vec<u64>, number_of_blinks: u8) {
+    for _ in 0..number_of_blinks {
+        let mut right_items = Vec::new();
+        for stone in &mut *stones {
+            if *stone == 0 {
+                *stone = 1;
+                continue;
+            }
+            let num_digits = (*stone as f64).log10().floor() as u32 + 1; // Calculate number of digits
+            if num_digits.is_multiple_of(2) {
+                let divisor = 10_u64.pow(num_digits / 2); // Find the split divisor
+                let left = *stone / divisor; // Get the left part
+                let right = *stone % divisor; // Get the right part
+
+                *stone = left;
+                right_items.push(right);
+            } else {
+                *stone *= 2024
+            }
+        }
+        stones.extend(right_items);
+    }
+}
+
+fn recursive_solution(stones: &[u64], number_of_blinks: u8) -> u64 {
+    let mut answer = 0;
+    let mut cache: HashMap<(u64, u8), u64> = HashMap::new();
+    for stone in stones {
+        answer += count_stones_recursive(*stone, number_of_blinks, &mut cache);
+    }
+    answer
+}
+
+fn count_stones_recursive(value: u64, depth: u8, cache: &mut HashMap<(u64, u8), u64>) -> u64 {
+    if let Some(&result) = cache.get(&(value, depth)) {
+        return result;
+    }
+    if depth == 0 {
+        return 1;
+    }
+
+    let result = if value == 0 {
+        count_stones_recursive(1, depth - 1, cache)
+    } else {
+        let num_digits = (value as f64).log10().floor() as u32 + 1;
+        if num_digits.is_multiple_of(2) {
+            let divisor = 10_u64.pow(num_digits / 2);
+            let left = value / divisor;
+            let right = value % divisor;
+
+            count_stones_recursive(left, depth - 1, cache)
+                + count_stones_recursive(right, depth - 1, cache)
+        } else {
+            count_stones_recursive(value * 2024, depth - 1, cache)
+        }
+    };
+
+    cache.insert((value, depth), result);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve_example;
+
+    const TEST: &str = "\
+125 17
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(55312, solve_example(Day11::part1, TEST));
+    }
+}
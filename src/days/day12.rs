@@ -0,0 +1,69 @@
+use crate::board_matrix::Board;
+use crate::regions::find_regions;
+use crate::solution::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: &'static str = "12";
+
+    type Input = Board<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: &mut R) -> Result<Board<char>> {
+        Board::parse(reader)
+    }
+
+    fn solve_part1(region_map: &Board<char>) -> Result<usize> {
+        let total_price = find_regions(region_map)
+            .regions
+            .iter()
+            .map(|region| region.area() * region.perimeter)
+            .sum();
+
+        Ok(total_price)
+    }
+
+    fn solve_part2(region_map: &Board<char>) -> Result<usize> {
+        // With the bulk discount the fence price depends on the number of sides
+        let total_price = find_regions(region_map)
+            .regions
+            .iter()
+            .map(|region| region.area() * region.sides)
+            .sum();
+
+        Ok(total_price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve_example;
+
+    const TEST: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(1930, solve_example(Day12::part1, TEST));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(1206, solve_example(Day12::part2, TEST));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::board_matrix::{Board, FromChar, Move, MoveIterator, Point};
+use crate::parse::{self, Line};
+use crate::solution::Solution;
+use anyhow::*;
+use std::io::BufRead;
+
+#[derive(Copy, Clone)]
+enum FieldType {
+    Barrier,
+    Obstacle,
+    Empty,
+    Robot,
+}
+
+impl FromChar for FieldType {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(FieldType::Barrier),
+            'O' => Some(FieldType::Obstacle),
+            '.' => Some(FieldType::Empty),
+            '@' => Some(FieldType::Robot),
+            _ => None,
+        }
+    }
+}
+
+pub struct Day15;
+
+/// Map of the warehouse followed by the moves of the robot.
+pub struct Warehouse {
+    map: Board<FieldType>,
+    moves: Vec<Move>,
+}
+
+impl Solution for Day15 {
+    const DAY: &'static str = "15";
+
+    type Input = Warehouse;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: &mut R) -> Result<Warehouse> {
+        let sections = parse::read_sections(reader)?;
+        let (map_section, moves_sections) = sections
+            .split_first()
+            .ok_or_else(|| anyhow!("The input is empty"))?;
+        let map = Board::from_lines(map_section)?;
+        let moves = read_moves(moves_sections.concat().as_slice())?;
+        Ok(Warehouse { map, moves })
+    }
+
+    fn solve_part1(warehouse: &Warehouse) -> Result<usize> {
+        let mut warehouse_map = warehouse.map.clone();
+        let moves = &warehouse.moves;
+
+        let mut current_position = find_initial_position(&warehouse_map)
+            .ok_or_else(|| anyhow!("There is no robot '@' on the map"))?;
+        remove_initial_position_character(&mut warehouse_map, &current_position)?;
+
+        for direction in moves {
+            let new_position = current_position + direction.coordinates();
+            let next_value = warehouse_map.get_value_from_point(&new_position)?;
+
+            match next_value {
+                FieldType::Empty => {
+                    current_position = new_position;
+                }
+                FieldType::Obstacle => {
+                    let (last_index, last_value) =
+                        get_last_index_behind_boxes(&warehouse_map, &current_position, direction)?;
+                    if let FieldType::Empty = last_value {
+                        current_position = new_position;
+                        warehouse_map.set_value_from_point(&current_position, FieldType::Empty)?;
+                        warehouse_map.set_value_from_point(&last_index, FieldType::Obstacle)?;
+                    }
+                }
+                FieldType::Barrier => continue,
+                FieldType::Robot => {
+                    return Err(anyhow!(
+                        "Robot character should be removed during initialization!"
+                    ));
+                }
+            }
+        }
+
+        Ok(calculate_gps_score(&warehouse_map))
+    }
+}
+
+fn read_moves(lines: &[Line]) -> Result<Vec<Move>> {
+    let mut moves: Vec<Move> = vec![];
+    for line in lines {
+        for (offset, c) in line.text.trim_end().char_indices() {
+            let direction = Move::from_arrow(c)
+                .ok_or_else(|| line.error(offset, format!("invalid move '{}'", c)))?;
+            moves.push(direction);
+        }
+    }
+    Ok(moves)
+}
+
+fn find_initial_position(map: &Board<FieldType>) -> Option<Point> {
+    for (point, value) in map.iter() {
+        if let FieldType::Robot = value {
+            return Some(point);
+        }
+    }
+    None
+}
+
+fn remove_initial_position_character(
+    map: &mut Board<FieldType>,
+    initial_position: &Point,
+) -> Result<()> {
+    map.set_value_from_point(initial_position, FieldType::Empty)?;
+    Ok(())
+}
+
+fn get_last_index_behind_boxes(
+    map: &Board<FieldType>,
+    initial_position: &Point,
+    direction: &Move,
+) -> Result<(Point, FieldType)> {
+    MoveIterator::new(map, initial_position, direction)
+        .skip(1)
+        .find(|(_, value)| !matches!(value, FieldType::Obstacle))
+        .map(|(point, value)| (point, *value))
+        // The map is restricted by barriers '#', so going outside of it means an invalid map
+        .ok_or_else(|| anyhow!("The boxes reach the edge of the map"))
+}
+
+fn calculate_gps_score(map: &Board<FieldType>) -> usize {
+    let mut score = 0;
+    for (point, value) in map.iter() {
+        if let FieldType::Obstacle = value {
+            let row_index = point.y as usize;
+            let col_index = point.x as usize;
+            score += 100 * row_index + col_index
+        }
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve_example;
+
+    const TEST: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    const TEST_2: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(2028, solve_example(Day15::part1, TEST));
+    }
+
+    #[test]
+    fn part1_second_example() {
+        assert_eq!(10092, solve_example(Day15::part1, TEST_2));
+    }
+}
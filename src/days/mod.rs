@@ -0,0 +1,38 @@
+use crate::solution::DaySolution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day15;
+
+/// Registry of all solved days, in order.
+pub fn all() -> Vec<DaySolution> {
+    vec![
+        DaySolution::of::<day01::Day01>(),
+        DaySolution::of::<day02::Day02>(),
+        DaySolution::of::<day03::Day03>(),
+        DaySolution::of::<day04::Day04>(),
+        DaySolution::of::<day05::Day05>(),
+        DaySolution::of::<day06::Day06>(),
+        DaySolution::of::<day07::Day07>(),
+        DaySolution::of::<day09::Day09>(),
+        DaySolution::of::<day10::Day10>(),
+        DaySolution::of::<day11::Day11>(),
+        DaySolution::of::<day12::Day12>(),
+        DaySolution::of::<day15::Day15>(),
+    ]
+}
+
+/// Find a day in the registry by its number, e.g. `"7"` or `"07"`.
+pub fn find(day: &str) -> Option<DaySolution> {
+    let day = format!("{:0>2}", day);
+    all().into_iter().find(|solution| solution.day == day)
+}
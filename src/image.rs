@@ -0,0 +1,348 @@
+use crate::board_matrix::Board;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+}
+
+const HEATMAP_STOPS: [Rgb; 5] = [
+    Rgb(0, 0, 96),
+    Rgb(0, 128, 255),
+    Rgb(0, 200, 100),
+    Rgb(255, 220, 0),
+    Rgb(255, 32, 0),
+];
+
+/// `t` is clamped between 0 (cold) and 1 (hot).
+pub fn heat_color(t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0) * (HEATMAP_STOPS.len() - 1) as f64;
+    let index = (t.floor() as usize).min(HEATMAP_STOPS.len() - 2);
+    let fraction = t - index as f64;
+    let (Rgb(r0, g0, b0), Rgb(r1, g1, b1)) = (HEATMAP_STOPS[index], HEATMAP_STOPS[index + 1]);
+    let mix = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * fraction).round() as u8;
+    Rgb(mix(r0, r1), mix(g0, g1), mix(b0, b1))
+}
+
+/// `None` when the cell has no value, e.g. an unreachable cell of a distance map.
+pub trait HeatValue {
+    fn heat(&self) -> Option<f64>;
+}
+
+macro_rules! impl_heat_value {
+    ($($t:ty),*) => {
+        $(impl HeatValue for $t {
+            fn heat(&self) -> Option<f64> {
+                Some(*self as f64)
+            }
+        })*
+    };
+}
+
+impl_heat_value!(i8, i16, i32, i64, u8, u16, u32, u64, usize, f32, f64);
+
+impl<T: HeatValue> HeatValue for Option<T> {
+    fn heat(&self) -> Option<f64> {
+        self.as_ref()?.heat()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn from_board<T>(board: &Board<T>, color: impl Fn(&T) -> Rgb) -> Self {
+        Image {
+            width: board.n_cols,
+            height: board.n_rows,
+            pixels: board.iter().map(|(_, value)| color(value)).collect(),
+        }
+    }
+
+    /// Scaled between the lowest and the highest value on the board, cells without a value
+    /// are black.
+    pub fn heatmap<T: HeatValue>(board: &Board<T>) -> Self {
+        let (min, max) = board
+            .iter()
+            .filter_map(|(_, value)| value.heat())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), heat| {
+                (min.min(heat), max.max(heat))
+            });
+        let range = max - min;
+        Image::from_board(board, |value| match value.heat() {
+            None => Rgb::BLACK,
+            Some(_) if range <= 0.0 => heat_color(0.0),
+            Some(heat) => heat_color((heat - min) / range),
+        })
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn scaled(&self, factor: usize) -> Self {
+        let width = self.width * factor;
+        let height = self.height * factor;
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                pixels.push(self.pixel(x / factor, y / factor));
+            }
+        }
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Binary PPM (P6).
+    pub fn write_ppm<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        let bytes: Vec<u8> = self
+            .pixels
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b])
+            .collect();
+        out.write_all(&bytes)
+    }
+
+    /// 8-bit RGB PNG with uncompressed image data.
+    pub fn write_png<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        out.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // Bit depth 8, colour type RGB, default compression, filter and no interlacing
+        header.extend([8, 2, 0, 0, 0]);
+        write_png_chunk(out, b"IHDR", &header)?;
+
+        // Every row starts with its filter type, 0 = none
+        let mut scanlines = Vec::with_capacity(self.height * (3 * self.width + 1));
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            scanlines.push(0);
+            scanlines.extend(row.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]));
+        }
+        write_png_chunk(out, b"IDAT", &zlib_stored(&scanlines))?;
+
+        write_png_chunk(out, b"IEND", &[])
+    }
+
+    /// Save the image in the format given by the extension of the path, `.ppm` or `.png`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let format = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("ppm") => ImageFormat::Ppm,
+            Some("png") => ImageFormat::Png,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{} is neither a .ppm nor a .png file", path.display()),
+                ))
+            }
+        };
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write(format, &mut out)?;
+        out.flush()
+    }
+
+    pub fn write<W: Write>(&self, format: ImageFormat, out: &mut W) -> io::Result<()> {
+        match format {
+            ImageFormat::Ppm => self.write_ppm(out),
+            ImageFormat::Png => self.write_png(out),
+        }
+    }
+}
+
+fn write_png_chunk<W: Write>(out: &mut W, chunk_type: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(chunk_type)?;
+    out.write_all(data)?;
+    let crc = crc32(chunk_type.iter().chain(data).copied());
+    out.write_all(&crc.to_be_bytes())
+}
+
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+
+    let n_blocks = data.len().div_ceil(MAX_BLOCK).max(1);
+    let mut stream = Vec::with_capacity(data.len() + 5 * n_blocks + 6);
+    // Deflate with a 32K window, no preset dictionary, check bits making the header
+    // a multiple of 31
+    stream.extend([0x78, 0x01]);
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        stream.push(u8::from(is_last));
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xedb8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    table
+}
+
+fn crc32(bytes: impl Iterator<Item = u8>) -> u32 {
+    !bytes.fold(u32::MAX, |crc, byte| {
+        CRC32_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MODULUS: u32 = 65521;
+    let (a, b) = bytes.iter().fold((1, 0), |(a, b), &byte| {
+        let a = (a + byte as u32) % MODULUS;
+        (a, (b + a) % MODULUS)
+    });
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn black_and_white(c: &char) -> Rgb {
+        if *c == '#' {
+            Rgb::WHITE
+        } else {
+            Rgb::BLACK
+        }
+    }
+
+    #[test]
+    fn write_ppm_header_and_pixels() {
+        let board = Board::new(vec![vec!['#', '.']]);
+        let mut image = Vec::new();
+
+        Image::from_board(&board, black_and_white)
+            .write_ppm(&mut image)
+            .unwrap();
+        assert_eq!(image, b"P6\n2 1\n255\n\xff\xff\xff\x00\x00\x00");
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"123456789".iter().copied()), 0xcbf4_3926);
+        assert_eq!(crc32(b"IEND".iter().copied()), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn write_png_chunks() {
+        let board = Board::new(vec![vec!['#', '.'], vec!['.', '#']]);
+        let mut png = Vec::new();
+        Image::from_board(&board, black_and_white)
+            .write_png(&mut png)
+            .unwrap();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 2, 0, 0, 0, 2]);
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xae\x42\x60\x82");
+
+        // 2 rows of a filter byte and 2 pixels, in a single stored block
+        let idat_length = u32::from_be_bytes(png[33..37].try_into().unwrap());
+        assert_eq!(idat_length, 2 + 5 + 14 + 4);
+        assert_eq!(&png[37..41], b"IDAT");
+        assert_eq!(&png[43..48], &[1, 14, 0, 0xf1, 0xff]);
+    }
+
+    #[test]
+    fn split_large_image_into_stored_blocks() {
+        let data = vec![7; 70_000];
+        let stream = zlib_stored(&data);
+
+        assert_eq!(stream.len(), 2 + 2 * 5 + 70_000 + 4);
+        assert_eq!(&stream[2..7], &[0, 0xff, 0xff, 0, 0]);
+        assert_eq!(&stream[65_542..65_547], &[1, 0x71, 0x11, 0x8e, 0xee]);
+    }
+
+    #[test]
+    fn heatmap_of_distances() {
+        let board = Board::new(vec![vec![Some(0), Some(5), None, Some(10)]]);
+        let image = Image::heatmap(&board);
+
+        assert_eq!(image.pixel(0, 0), HEATMAP_STOPS[0]);
+        assert_eq!(image.pixel(1, 0), HEATMAP_STOPS[2]);
+        assert_eq!(image.pixel(2, 0), Rgb::BLACK);
+        assert_eq!(image.pixel(3, 0), HEATMAP_STOPS[4]);
+    }
+
+    #[test]
+    fn heatmap_of_heights() {
+        let board: Board<i8> = Board::from_text("0123\n4567\n");
+        let image = Image::heatmap(&board);
+
+        assert_eq!(image.pixel(0, 0), heat_color(0.0));
+        assert_eq!(image.pixel(3, 1), heat_color(1.0));
+    }
+
+    #[test]
+    fn scale_pixels() {
+        let board = Board::new(vec![vec!['#', '.']]);
+        let image = Image::from_board(&board, black_and_white).scaled(2);
+
+        assert_eq!((image.width, image.height), (4, 2));
+        assert_eq!(image.pixel(1, 1), Rgb::WHITE);
+        assert_eq!(image.pixel(2, 0), Rgb::BLACK);
+    }
+
+    #[test]
+    fn save_by_extension() {
+        let image = Image::from_board(&Board::new(vec![vec!['#']]), black_and_white);
+        let path = std::env::temp_dir().join(format!("image-{}.bmp", std::process::id()));
+
+        let error = image.save(&path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -1,5 +1,25 @@
+pub mod answers;
+pub mod bench;
 pub mod board_matrix;
+pub mod days;
+pub mod image;
 pub mod number_utils;
+pub mod parse;
+pub mod pattern;
+pub mod recorder;
+pub mod regions;
+pub mod render;
+pub mod scaffold;
+pub mod search;
+pub mod simulation;
+pub mod solution;
+pub mod sparse_grid;
+pub mod wrapping_board;
+
+use anyhow::*;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
 
 pub fn start_day(day: &str) {
     println!("Advent of Code 2024 - Day {:0>2}", day);
@@ -7,6 +27,29 @@ pub fn start_day(day: &str) {
 
 // Additional common functions
 
+/// Solve a part of the puzzle against an inline example, e.g. `solve_example(Day01::part1, TEST)`.
+///
+/// Panics when the part returns an error, so it is meant to be used in tests.
+pub fn solve_example<'a, T>(part: impl FnOnce(&mut &'a [u8]) -> Result<T>, example: &'a str) -> T {
+    match part(&mut example.as_bytes()) {
+        Result::Ok(answer) => answer,
+        Err(e) => panic!("Failed to solve the example: {:#}", e),
+    }
+}
+
+/// Open the personal puzzle input `input/NN.txt`.
+///
+/// Inputs are not committed to the repository, so a missing file is reported as skipped
+/// and `None` is returned instead of an error.
+pub fn open_personal_input(day: &str) -> Option<BufReader<File>> {
+    let path = solution::input_path(day);
+    if !Path::new(&path).exists() {
+        println!("Skipped, there is no {}", path);
+        return None;
+    }
+    File::open(path).ok().map(BufReader::new)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -15,4 +58,21 @@ mod tests {
     fn it_works() {
         start_day("00");
     }
+
+    #[test]
+    fn solve_example_returns_answer() {
+        let count_lines = |reader: &mut &[u8]| Ok(std::io::BufRead::lines(reader).count());
+        assert_eq!(solve_example(count_lines, "a\nb\n"), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to solve the example")]
+    fn solve_example_panics_on_error() {
+        solve_example(|_| Err::<usize, _>(anyhow!("Broken")), "");
+    }
+
+    #[test]
+    fn missing_personal_input_is_skipped() {
+        assert!(open_personal_input("00").is_none());
+    }
 }
@@ -4,8 +4,7 @@ pub fn concatenate_numbers(num1: u64, num2: u64) -> u64 {
 }
 
 fn get_number_of_digits(num: u64) -> u32 {
-    let num_digits = (num as f64).log10().floor() as u32 + 1;
-    num_digits
+    (num as f64).log10().floor() as u32 + 1
 }
 
 #[cfg(test)]
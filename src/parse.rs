@@ -0,0 +1,326 @@
+//! Parsing of the line and section based formats of puzzle inputs.
+//!
+//! Errors point to the line and column of the offending value, e.g.
+//! `line 3, column 5: invalid value 'x7'`.
+
+use anyhow::Result;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number in the puzzle input.
+    pub line: usize,
+    /// 1-based column (in characters) where the problem starts.
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Line of the puzzle input with its 1-based line number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub number: usize,
+    pub text: String,
+}
+
+impl Line {
+    pub fn new(number: usize, text: &str) -> Self {
+        Line {
+            number,
+            text: text.to_string(),
+        }
+    }
+
+    pub fn error(&self, byte_offset: usize, message: String) -> ParseError {
+        ParseError {
+            line: self.number,
+            column: self.text[..byte_offset].chars().count() + 1,
+            message,
+        }
+    }
+
+    /// Whitespace-separated values, e.g. `7 6 4 2 1`.
+    pub fn values<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        self.parse_tokens(0, whitespace_tokens(&self.text))
+    }
+
+    /// Values separated by a delimiter, e.g. `75,47,61` with `','`.
+    pub fn fields<T: FromStr>(&self, delimiter: char) -> Result<Vec<T>, ParseError> {
+        self.parse_tokens(0, delimited_tokens(&self.text, delimiter))
+    }
+
+    /// Exactly `N` values separated by a delimiter, e.g. `47|53` with `'|'`.
+    pub fn fields_exact<T: FromStr, const N: usize>(
+        &self,
+        delimiter: char,
+    ) -> Result<[T; N], ParseError> {
+        let fields: Vec<T> = self.fields(delimiter)?;
+        let n_fields = fields.len();
+        fields.try_into().map_err(|_| {
+            self.error(
+                0,
+                format!(
+                    "expected {} values separated by '{}', got {}",
+                    N, delimiter, n_fields
+                ),
+            )
+        })
+    }
+
+    /// A key followed by whitespace-separated values, e.g. `190: 10 19` with `':'`.
+    pub fn key_values<K: FromStr, V: FromStr>(
+        &self,
+        separator: char,
+    ) -> Result<(K, Vec<V>), ParseError> {
+        let separator_index = self.text.find(separator).ok_or_else(|| {
+            self.error(
+                self.text.len(),
+                format!("expected '{}' after the key", separator),
+            )
+        })?;
+        let key = &self.text[..separator_index];
+        let values_offset = separator_index + separator.len_utf8();
+
+        let key_offset = key.len() - key.trim_start().len();
+        let key = self.parse_token(key_offset, key.trim())?;
+        let values = self.parse_tokens(
+            values_offset,
+            whitespace_tokens(&self.text[values_offset..]),
+        )?;
+        Ok((key, values))
+    }
+
+    fn parse_token<T: FromStr>(&self, byte_offset: usize, token: &str) -> Result<T, ParseError> {
+        token
+            .parse::<T>()
+            .map_err(|_| self.error(byte_offset, format!("invalid value '{}'", token)))
+    }
+
+    fn parse_tokens<'a, T: FromStr>(
+        &self,
+        base_offset: usize,
+        tokens: impl Iterator<Item = (usize, &'a str)>,
+    ) -> Result<Vec<T>, ParseError> {
+        tokens
+            .map(|(offset, token)| self.parse_token(base_offset + offset, token))
+            .collect()
+    }
+}
+
+/// Tokens separated by whitespace, with their byte offsets.
+fn whitespace_tokens(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut token_start = None;
+    for (index, c) in text.char_indices() {
+        match (c.is_whitespace(), token_start) {
+            (true, Some(start)) => {
+                tokens.push((start, &text[start..index]));
+                token_start = None;
+            }
+            (false, None) => token_start = Some(index),
+            _ => {}
+        }
+    }
+    if let Some(start) = token_start {
+        tokens.push((start, &text[start..]));
+    }
+    tokens.into_iter()
+}
+
+/// Trimmed tokens separated by a delimiter, with their byte offsets.
+fn delimited_tokens(text: &str, delimiter: char) -> impl Iterator<Item = (usize, &str)> {
+    let mut token_start = 0;
+    text.split(delimiter).map(move |token| {
+        let offset = token_start + token.len() - token.trim_start().len();
+        token_start += token.len() + delimiter.len_utf8();
+        (offset, token.trim())
+    })
+}
+
+/// Read all lines of the puzzle input.
+pub fn read_lines<R: BufRead>(reader: R) -> Result<Vec<Line>> {
+    let mut lines = Vec::new();
+    for (index, text) in reader.lines().enumerate() {
+        lines.push(Line {
+            number: index + 1,
+            text: text?,
+        });
+    }
+    Ok(lines)
+}
+
+/// Read the puzzle input split into sections separated by blank lines.
+///
+/// Blank lines at the start and the end of the input don't produce empty sections.
+pub fn read_sections<R: BufRead>(reader: R) -> Result<Vec<Vec<Line>>> {
+    let mut sections = Vec::new();
+    let mut current_section = Vec::new();
+    for line in read_lines(reader)? {
+        if line.text.trim().is_empty() {
+            if !current_section.is_empty() {
+                sections.push(std::mem::take(&mut current_section));
+            }
+            continue;
+        }
+        current_section.push(line);
+    }
+    if !current_section.is_empty() {
+        sections.push(current_section);
+    }
+    Ok(sections)
+}
+
+/// Whitespace-separated values of every non-blank line, e.g. `7 6 4 2 1`.
+pub fn values_per_line<T: FromStr>(lines: &[Line]) -> Result<Vec<Vec<T>>, ParseError> {
+    non_blank(lines).map(Line::values).collect()
+}
+
+/// Values of every non-blank line split into `N` columns, e.g. `3   4` for two columns.
+pub fn columns<T: FromStr, const N: usize>(lines: &[Line]) -> Result<[Vec<T>; N], ParseError> {
+    let mut columns: [Vec<T>; N] = std::array::from_fn(|_| Vec::new());
+    for line in non_blank(lines) {
+        let values: Vec<T> = line.values()?;
+        if values.len() != N {
+            return Err(line.error(0, format!("expected {} columns, got {}", N, values.len())));
+        }
+        for (column, value) in columns.iter_mut().zip(values) {
+            column.push(value);
+        }
+    }
+    Ok(columns)
+}
+
+/// A key followed by whitespace-separated values on every non-blank line, e.g. `190: 10 19`.
+pub fn key_values_per_line<K: FromStr, V: FromStr>(
+    lines: &[Line],
+    separator: char,
+) -> Result<Vec<(K, Vec<V>)>, ParseError> {
+    non_blank(lines)
+        .map(|line| line.key_values(separator))
+        .collect()
+}
+
+/// Values separated by a delimiter on every non-blank line, e.g. `75,47,61` with `','`.
+pub fn fields_per_line<T: FromStr>(
+    lines: &[Line],
+    delimiter: char,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    non_blank(lines)
+        .map(|line| line.fields(delimiter))
+        .collect()
+}
+
+fn non_blank(lines: &[Line]) -> impl Iterator<Item = &Line> {
+    lines.iter().filter(|line| !line.text.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_lines_with_numbers() {
+        let lines = read_lines("a\nb\n".as_bytes()).unwrap();
+        assert_eq!(lines, vec![Line::new(1, "a"), Line::new(2, "b")]);
+    }
+
+    #[test]
+    fn split_into_sections() {
+        let sections = read_sections("\na\nb\n\n\nc\n\n".as_bytes()).unwrap();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0], vec![Line::new(2, "a"), Line::new(3, "b")]);
+        assert_eq!(sections[1], vec![Line::new(6, "c")]);
+    }
+
+    #[test]
+    fn parse_values_per_line() {
+        let lines = read_lines("7 6 4\n1  2\n".as_bytes()).unwrap();
+        let values: Vec<Vec<i32>> = values_per_line(&lines).unwrap();
+        assert_eq!(values, vec![vec![7, 6, 4], vec![1, 2]]);
+    }
+
+    #[test]
+    fn report_line_and_column_of_invalid_value() {
+        let lines = read_lines("7 6 4\n1  x2 3\n".as_bytes()).unwrap();
+        let error = values_per_line::<i32>(&lines).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.to_string(), "line 2, column 4: invalid value 'x2'");
+    }
+
+    #[test]
+    fn parse_two_columns() {
+        let lines = read_lines("3   4\n4   3\n".as_bytes()).unwrap();
+        let [left, right] = columns::<u32, 2>(&lines).unwrap();
+        assert_eq!(left, vec![3, 4]);
+        assert_eq!(right, vec![4, 3]);
+    }
+
+    #[test]
+    fn reject_wrong_number_of_columns() {
+        let lines = read_lines("3   4\n4\n".as_bytes()).unwrap();
+        let error = columns::<u32, 2>(&lines).unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.message, "expected 2 columns, got 1");
+    }
+
+    #[test]
+    fn parse_key_values() {
+        let lines = read_lines("190: 10 19\n3267: 81 40 27\n".as_bytes()).unwrap();
+        let equations: Vec<(u64, Vec<u64>)> = key_values_per_line(&lines, ':').unwrap();
+        assert_eq!(equations[0], (190, vec![10, 19]));
+        assert_eq!(equations[1], (3267, vec![81, 40, 27]));
+    }
+
+    #[test]
+    fn report_column_of_invalid_value_after_key() {
+        let error = Line::new(1, "190: 10 1a9")
+            .key_values::<u64, u64>(':')
+            .unwrap_err();
+        assert_eq!(error.column, 9);
+    }
+
+    #[test]
+    fn report_missing_key_separator() {
+        let error = Line::new(4, "190 10")
+            .key_values::<u64, u64>(':')
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (4, 7));
+    }
+
+    #[test]
+    fn parse_delimited_fields() {
+        let lines = read_lines("75,47, 61\n".as_bytes()).unwrap();
+        let pages: Vec<Vec<i32>> = fields_per_line(&lines, ',').unwrap();
+        assert_eq!(pages, vec![vec![75, 47, 61]]);
+    }
+
+    #[test]
+    fn report_column_of_invalid_field() {
+        let error = Line::new(1, "75,47, x").fields::<i32>(',').unwrap_err();
+        assert_eq!(error.column, 8);
+    }
+
+    #[test]
+    fn parse_exact_number_of_fields() {
+        let [left, right] = Line::new(1, "47|53").fields_exact::<i32, 2>('|').unwrap();
+        assert_eq!((left, right), (47, 53));
+
+        let error = Line::new(1, "47|53|1")
+            .fields_exact::<i32, 2>('|')
+            .unwrap_err();
+        assert_eq!(error.message, "expected 2 values separated by '|', got 3");
+    }
+}
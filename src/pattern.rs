@@ -0,0 +1,210 @@
+use crate::board_matrix::{Board, BoardError, Move, MoveIterator, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WordMatch {
+    pub start: Point,
+    pub direction: Move,
+}
+
+/// A palindrome is found twice at the same place, once in each direction.
+pub fn find_word<T: PartialEq>(
+    board: &Board<T>,
+    word: &[T],
+    directions: impl IntoIterator<Item = Move>,
+) -> Vec<WordMatch> {
+    let directions: Vec<Move> = directions.into_iter().collect();
+    let Some(first_letter) = word.first() else {
+        return Vec::new();
+    };
+
+    let mut matches = Vec::new();
+    for (start, value) in board.iter() {
+        if value != first_letter {
+            continue;
+        }
+        for &direction in &directions {
+            let cells = MoveIterator::new(board, &start, &direction)
+                .max_steps(word.len() - 1)
+                .map(|(_, value)| value);
+            if cells.eq(word.iter()) {
+                matches.push(WordMatch { start, direction });
+            }
+        }
+    }
+    matches
+}
+
+/// The `None` cells match anything.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template<T> {
+    cells: Board<Option<T>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TemplateMatch {
+    pub top_left: Point,
+    /// Index in the list of searched templates.
+    pub variant: usize,
+}
+
+impl Template<char> {
+    pub fn parse(text: &str, wildcard: char) -> Result<Self, BoardError> {
+        let rows = text
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| if c == wildcard { None } else { Some(c) })
+                    .collect()
+            })
+            .collect();
+        Ok(Template::new(Board::try_new(rows)?))
+    }
+}
+
+impl<T> Template<T> {
+    pub fn new(cells: Board<Option<T>>) -> Self {
+        Template { cells }
+    }
+
+    pub fn matches_at(&self, board: &Board<T>, top_left: Point) -> bool
+    where
+        T: PartialEq,
+    {
+        self.cells.iter().all(|(offset, expected)| match expected {
+            None => board.contains(&(top_left + offset)),
+            Some(expected) => board.get(&(top_left + offset)) == Some(expected),
+        })
+    }
+}
+
+impl<T: Clone> Template<T> {
+    /// Clockwise.
+    pub fn rotated(&self) -> Self {
+        Template::new(self.cells.rotate90())
+    }
+
+    pub fn reflected(&self) -> Self {
+        Template::new(self.cells.flip_h())
+    }
+
+    /// Distinct rotations and reflections, the template itself first.
+    pub fn variants(&self) -> Vec<Self>
+    where
+        T: PartialEq,
+    {
+        let mut variants: Vec<Self> = Vec::with_capacity(8);
+        for start in [self.clone(), self.reflected()] {
+            let mut template = start;
+            for _ in 0..4 {
+                let rotated = template.rotated();
+                if !variants.contains(&template) {
+                    variants.push(template);
+                }
+                template = rotated;
+            }
+        }
+        variants
+    }
+}
+
+pub fn find_templates<T: PartialEq>(
+    board: &Board<T>,
+    templates: &[Template<T>],
+) -> Vec<TemplateMatch> {
+    let mut matches = Vec::new();
+    for (variant, template) in templates.iter().enumerate() {
+        let (Some(last_row), Some(last_col)) = (
+            board.n_rows.checked_sub(template.cells.n_rows),
+            board.n_cols.checked_sub(template.cells.n_cols),
+        ) else {
+            continue;
+        };
+        for row in 0..=last_row {
+            for col in 0..=last_col {
+                let top_left = Point::from_row_col(row, col);
+                if template.matches_at(board, top_left) {
+                    matches.push(TemplateMatch { top_left, variant });
+                }
+            }
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_word_in_directions() {
+        let board = Board::from_text("XMAS\nMM..\nA.A.\nS..S\n");
+        let word: Vec<char> = "XMAS".chars().collect();
+
+        let matches = find_word(&board, &word, Move::all());
+        assert_eq!(matches.len(), 3);
+        assert!(matches.contains(&WordMatch {
+            start: Point::new(0, 0),
+            direction: Move::BottomRight
+        }));
+
+        let matches = find_word(&board, &word, [Move::Right]);
+        assert_eq!(
+            matches,
+            vec![WordMatch {
+                start: Point::new(0, 0),
+                direction: Move::Right
+            }]
+        );
+    }
+
+    #[test]
+    fn word_cut_by_the_edge_isnt_found() {
+        let board = Board::from_text("..XMA\n");
+        let word: Vec<char> = "XMAS".chars().collect();
+
+        assert!(find_word(&board, &word, Move::all()).is_empty());
+    }
+
+    #[test]
+    fn variants_of_template() {
+        let cross = Template::parse("M.S\n.A.\nM.S", '.').unwrap();
+        assert_eq!(cross.variants().len(), 4);
+
+        let asymmetric = Template::parse("AB\nC.", '.').unwrap();
+        assert_eq!(asymmetric.variants().len(), 8);
+    }
+
+    #[test]
+    fn rotate_template() {
+        let template = Template::parse("AB\nCD\nEF", '.').unwrap();
+        assert_eq!(
+            template.rotated(),
+            Template::parse("ECA\nFDB", '.').unwrap()
+        );
+        assert_eq!(
+            template.reflected(),
+            Template::parse("BA\nDC\nFE", '.').unwrap()
+        );
+    }
+
+    #[test]
+    fn find_template_with_wildcards() {
+        let board = Board::from_text("MXS.\nMAM.\nMXS.\n.S.S\n");
+        let cross = Template::parse("M.S\n.A.\nM.S", '.').unwrap();
+
+        let matches = find_templates(&board, &cross.variants());
+        assert_eq!(
+            matches,
+            vec![TemplateMatch {
+                top_left: Point::new(0, 0),
+                variant: 0
+            }]
+        );
+    }
+
+    #[test]
+    fn template_larger_than_board() {
+        let template = Template::parse("AB\nCD", '.').unwrap();
+        assert!(find_templates(&Board::from_text("A\n"), &[template]).is_empty());
+    }
+}
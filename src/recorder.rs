@@ -0,0 +1,269 @@
+use crate::board_matrix::{Board, Point, ToChar};
+use crate::image::{Image, ImageFormat, Rgb};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+/// A full board only when there's no previous one of the same size.
+#[derive(Debug, Clone)]
+enum Change<T> {
+    Keyframe(Board<T>),
+    Diff(Vec<(Point, T)>),
+}
+
+/// Stores only the changed cells between consecutive frames.
+#[derive(Debug, Clone)]
+pub struct Recorder<T> {
+    changes: Vec<Change<T>>,
+    last: Option<Board<T>>,
+}
+
+impl<T> Default for Recorder<T> {
+    fn default() -> Self {
+        Recorder {
+            changes: Vec::new(),
+            last: None,
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> Recorder<T> {
+    pub fn new() -> Self {
+        Recorder::default()
+    }
+
+    pub fn record(&mut self, board: &Board<T>) {
+        let change = match &mut self.last {
+            Some(last) if last.n_rows == board.n_rows && last.n_cols == board.n_cols => {
+                let diff = board
+                    .iter()
+                    .zip(last.iter())
+                    .filter(|((_, value), (_, last_value))| value != last_value)
+                    .map(|((point, value), _)| (point, value.clone()))
+                    .collect();
+                last.clone_from(board);
+                Change::Diff(diff)
+            }
+            _ => {
+                self.last = Some(board.clone());
+                Change::Keyframe(board.clone())
+            }
+        };
+        self.changes.push(change);
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Rebuilt from the changes one after another.
+    pub fn frames(&self) -> impl Iterator<Item = Board<T>> + '_ {
+        self.changes
+            .iter()
+            .scan(None::<Board<T>>, |current, change| {
+                match change {
+                    Change::Keyframe(board) => *current = Some(board.clone()),
+                    Change::Diff(diff) => {
+                        let board = current.as_mut()?;
+                        for (point, value) in diff {
+                            board[point] = value.clone();
+                        }
+                    }
+                }
+                current.clone()
+            })
+    }
+
+    /// Files are named `frame_00000.png`, `frame_00001.png`... (or `.ppm`).
+    pub fn save_frames(
+        &self,
+        directory: &Path,
+        format: ImageFormat,
+        color: impl Fn(&T) -> Rgb,
+    ) -> io::Result<Vec<PathBuf>> {
+        std::fs::create_dir_all(directory)?;
+        let mut paths = Vec::with_capacity(self.len());
+        for (index, board) in self.frames().enumerate() {
+            let path = directory.join(format!("frame_{:05}.{}", index, format.extension()));
+            let mut out = BufWriter::new(File::create(&path)?);
+            Image::from_board(&board, &color).write(format, &mut out)?;
+            out.flush()?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    pub fn save_ppm_frames(
+        &self,
+        directory: &Path,
+        color: impl Fn(&T) -> Rgb,
+    ) -> io::Result<Vec<PathBuf>> {
+        self.save_frames(directory, ImageFormat::Ppm, color)
+    }
+}
+
+impl<T: Clone + PartialEq + ToChar> Recorder<T> {
+    pub fn replay(&self, frame_duration: Duration) -> io::Result<()> {
+        self.replay_to(&mut io::stdout().lock(), frame_duration)
+    }
+
+    pub fn replay_to<W: Write>(&self, out: &mut W, frame_duration: Duration) -> io::Result<()> {
+        for (index, board) in self.frames().enumerate() {
+            write!(out, "{}{}", CLEAR_SCREEN, board)?;
+            writeln!(out, "Step {}/{}", index, self.len() - 1)?;
+            out.flush()?;
+            std::thread::sleep(frame_duration);
+        }
+        Ok(())
+    }
+
+    /// Asciicast v2, which can be played with `asciinema play`.
+    pub fn write_asciicast<W: Write>(
+        &self,
+        out: &mut W,
+        frame_duration: Duration,
+    ) -> io::Result<()> {
+        let (width, height) = self.frames().fold((0, 0), |(width, height), board| {
+            (width.max(board.n_cols), height.max(board.n_rows))
+        });
+        writeln!(
+            out,
+            "{{\"version\": 2, \"width\": {}, \"height\": {}}}",
+            width, height
+        )?;
+        for (index, board) in self.frames().enumerate() {
+            let time = frame_duration.as_secs_f64() * index as f64;
+            // A newline after the last row would scroll the top row off a `height` tall screen
+            let board = board.to_string();
+            let screen =
+                format!("{}{}", CLEAR_SCREEN, board.trim_end_matches('\n')).replace('\n', "\r\n");
+            writeln!(out, "[{:.6}, \"o\", \"{}\"]", time, json_escape(&screen))?;
+        }
+        Ok(())
+    }
+}
+
+fn json_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Robot `@` walking right along a corridor.
+    fn walk() -> Recorder<char> {
+        let mut board = Board::new(vec![vec!['@', '.', '.']]);
+        let mut recorder = Recorder::new();
+        recorder.record(&board);
+        for col in 1..3 {
+            board[Point::new(col - 1, 0)] = '.';
+            board[Point::new(col, 0)] = '@';
+            recorder.record(&board);
+        }
+        recorder
+    }
+
+    #[test]
+    fn rebuild_frames_from_diffs() {
+        let recorder = walk();
+
+        let frames: Vec<String> = recorder.frames().map(|board| board.render()).collect();
+        assert_eq!(frames, vec!["@..\n", ".@.\n", "..@\n"]);
+        assert!(matches!(&recorder.changes[1], Change::Diff(diff) if diff.len() == 2));
+    }
+
+    #[test]
+    fn board_of_other_size_is_a_keyframe() {
+        let mut recorder = walk();
+        recorder.record(&Board::new(vec![vec!['#'], vec!['@']]));
+
+        assert!(matches!(recorder.changes[3], Change::Keyframe(_)));
+        assert_eq!(recorder.frames().last().unwrap().render(), "#\n@\n");
+    }
+
+    #[test]
+    fn replay_clears_screen_between_frames() {
+        let mut out = Vec::new();
+        walk().replay_to(&mut out, Duration::ZERO).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.matches(CLEAR_SCREEN).count(), 3);
+        assert!(text.ends_with("..@\nStep 2/2\n"));
+    }
+
+    #[test]
+    fn export_asciicast() {
+        let mut out = Vec::new();
+        walk()
+            .write_asciicast(&mut out, Duration::from_millis(500))
+            .unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "{\"version\": 2, \"width\": 3, \"height\": 1}");
+        assert_eq!(lines[2], "[0.500000, \"o\", \"\\u001b[H\\u001b[2J.@.\"]");
+    }
+
+    #[test]
+    fn asciicast_keeps_the_top_row_on_screen() {
+        let mut recorder = Recorder::new();
+        recorder.record(&Board::new(vec![vec!['#', '#'], vec!['@', '.']]));
+        let mut out = Vec::new();
+        recorder
+            .write_asciicast(&mut out, Duration::from_millis(500))
+            .unwrap();
+
+        // Two rows on a screen of height 2 need only one line break
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "{\"version\": 2, \"width\": 2, \"height\": 2}");
+        assert_eq!(
+            lines[1],
+            "[0.000000, \"o\", \"\\u001b[H\\u001b[2J##\\r\\n@.\"]"
+        );
+    }
+
+    #[test]
+    fn save_frames_as_images() {
+        let directory = std::env::temp_dir().join(format!("recorder-{}", std::process::id()));
+
+        let paths = walk()
+            .save_ppm_frames(
+                &directory,
+                |c| {
+                    if *c == '@' {
+                        Rgb::WHITE
+                    } else {
+                        Rgb::BLACK
+                    }
+                },
+            )
+            .unwrap();
+        assert_eq!(paths.len(), 3);
+        let image = std::fs::read(&paths[2]).unwrap();
+        assert!(image.ends_with(&[0, 0, 0, 0, 0, 0, 255, 255, 255]));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
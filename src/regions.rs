@@ -0,0 +1,164 @@
+use crate::board_matrix::{Board, Point};
+
+const UNLABELLED: usize = usize::MAX;
+
+const CORNER_DIRECTIONS: [(Point, Point); 4] = [
+    (Point { x: 0, y: -1 }, Point { x: 1, y: 0 }),
+    (Point { x: 1, y: 0 }, Point { x: 0, y: 1 }),
+    (Point { x: 0, y: 1 }, Point { x: -1, y: 0 }),
+    (Point { x: -1, y: 0 }, Point { x: 0, y: -1 }),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub id: usize,
+    pub points: Vec<Point>,
+    /// Including the edges around holes.
+    pub perimeter: usize,
+    pub sides: usize,
+    pub min: Point,
+    /// Inclusive.
+    pub max: Point,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Regions {
+    pub labels: Board<usize>,
+    pub regions: Vec<Region>,
+}
+
+/// The regions are flood filled with an explicit stack, so a large region can't overflow
+/// the call stack.
+pub fn find_regions<T: PartialEq>(board: &Board<T>) -> Regions {
+    let mut labels = Board::filled(board.n_rows, board.n_cols, UNLABELLED);
+    let mut regions = Vec::new();
+
+    for (start, value) in board.iter() {
+        if labels[&start] != UNLABELLED {
+            continue;
+        }
+
+        let id = regions.len();
+        let mut region = Region {
+            id,
+            points: Vec::new(),
+            perimeter: 0,
+            sides: 0,
+            min: start,
+            max: start,
+        };
+        labels[&start] = id;
+        let mut stack = vec![start];
+
+        while let Some(point) = stack.pop() {
+            for (neighbor, neighbor_value) in board.neighbors4_or_outside(&point) {
+                if neighbor_value != Some(value) {
+                    region.perimeter += 1;
+                    continue;
+                }
+                if labels[&neighbor] == UNLABELLED {
+                    labels[&neighbor] = id;
+                    stack.push(neighbor);
+                }
+            }
+            region.sides += count_corners(board, &point, value);
+            region.min = Point::new(region.min.x.min(point.x), region.min.y.min(point.y));
+            region.max = Point::new(region.max.x.max(point.x), region.max.y.max(point.y));
+            region.points.push(point);
+        }
+        regions.push(region);
+    }
+
+    Regions { labels, regions }
+}
+
+// Both the convex and the concave corners
+fn count_corners<T: PartialEq>(board: &Board<T>, point: &Point, value: &T) -> usize {
+    let is_same = |offset: &Point| board.get(&(*point + *offset)) == Some(value);
+
+    CORNER_DIRECTIONS
+        .iter()
+        .filter(|(first, second)| {
+            let diagonal = *first + *second;
+            match (is_same(first), is_same(second)) {
+                (false, false) => true,
+                (true, true) => !is_same(&diagonal),
+                _ => false,
+            }
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(regions: &Regions) -> Vec<(usize, usize, usize)> {
+        regions
+            .regions
+            .iter()
+            .map(|region| (region.area(), region.perimeter, region.sides))
+            .collect()
+    }
+
+    #[test]
+    fn measure_regions() {
+        let regions = find_regions(&Board::<char>::from_text("AAAA\nBBCD\nBBCC\nEEEC\n"));
+
+        // Regions are ordered by their first cell: A, B, C, D, E
+        assert_eq!(
+            summary(&regions),
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+        assert_eq!(regions.labels[Point { x: 3, y: 3 }], 2);
+    }
+
+    #[test]
+    fn count_sides_of_region_with_holes() {
+        let regions = find_regions(&Board::<char>::from_text(
+            "OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n",
+        ));
+
+        let outer = &regions.regions[0];
+        assert_eq!((outer.area(), outer.perimeter, outer.sides), (21, 36, 20));
+        assert_eq!(regions.regions.len(), 5);
+    }
+
+    #[test]
+    fn count_sides_of_concave_region() {
+        let regions = find_regions(&Board::<char>::from_text(
+            "EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE\n",
+        ));
+
+        let price: usize = regions
+            .regions
+            .iter()
+            .map(|region| region.area() * region.sides)
+            .sum();
+        assert_eq!(price, 236);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let regions = find_regions(&Board::<char>::from_text("...\n.#.\n.##\n"));
+
+        let region = &regions.regions[regions.labels[Point { x: 1, y: 1 }]];
+        assert_eq!(region.min, Point { x: 1, y: 1 });
+        assert_eq!(region.max, Point { x: 2, y: 2 });
+    }
+
+    #[test]
+    fn large_region_doesnt_overflow_the_stack() {
+        let regions = find_regions(&Board::filled(500, 500, 'A'));
+
+        assert_eq!(regions.regions.len(), 1);
+        assert_eq!(regions.regions[0].area(), 250_000);
+        assert_eq!(regions.regions[0].sides, 4);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::board_matrix::{Board, Point, ToChar};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn ansi_code(&self) -> &'static str {
+        match self {
+            Color::Red => "1;31",
+            Color::Green => "1;32",
+            Color::Yellow => "1;33",
+            Color::Blue => "1;34",
+            Color::Magenta => "1;35",
+            Color::Cyan => "1;36",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Highlight {
+    points: Vec<Point>,
+    color: Color,
+    glyph: Option<char>,
+}
+
+impl Highlight {
+    pub fn new(points: impl IntoIterator<Item = Point>, color: Color) -> Self {
+        Highlight {
+            points: points.into_iter().collect(),
+            color,
+            glyph: None,
+        }
+    }
+
+    /// Also marks the points when the colours are off.
+    pub fn glyph(mut self, glyph: char) -> Self {
+        self.glyph = Some(glyph);
+        self
+    }
+}
+
+/// When several highlights contain the same point, the last one wins.
+#[derive(Debug, Clone)]
+pub struct BoardRenderer<'a, T> {
+    board: &'a Board<T>,
+    highlights: HashMap<Point, (Color, Option<char>)>,
+    ansi: bool,
+    ruler: bool,
+}
+
+impl<T: ToChar> Board<T> {
+    pub fn renderer(&self) -> BoardRenderer<'_, T> {
+        BoardRenderer {
+            board: self,
+            highlights: HashMap::new(),
+            ansi: false,
+            ruler: false,
+        }
+    }
+}
+
+impl<'a, T: ToChar> BoardRenderer<'a, T> {
+    pub fn highlight(mut self, highlight: Highlight) -> Self {
+        for point in highlight.points {
+            self.highlights
+                .insert(point, (highlight.color, highlight.glyph));
+        }
+        self
+    }
+
+    /// Off by default, so the text can be compared in tests.
+    pub fn ansi(mut self, enabled: bool) -> Self {
+        self.ansi = enabled;
+        self
+    }
+
+    pub fn ruler(mut self) -> Self {
+        self.ruler = true;
+        self
+    }
+
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn print(&self) {
+        print!("{}", self);
+    }
+
+    fn write_column_ruler(
+        &self,
+        f: &mut Formatter<'_>,
+        row_label_width: usize,
+    ) -> std::fmt::Result {
+        let n_cols = self.board.n_cols;
+        let mut place = 1;
+        let mut lines = Vec::new();
+        loop {
+            let line: String = (0..n_cols)
+                .map(|col| char::from_digit(((col / place) % 10) as u32, 10).unwrap())
+                .collect();
+            lines.push(line);
+            if n_cols <= place * 10 {
+                break;
+            }
+            place *= 10;
+        }
+        for line in lines.iter().rev() {
+            writeln!(f, "{:width$} {}", "", line, width = row_label_width)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: ToChar> Display for BoardRenderer<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let row_label_width = self.board.n_rows.saturating_sub(1).to_string().len();
+        if self.ruler {
+            self.write_column_ruler(f, row_label_width)?;
+        }
+
+        for (row, cells) in self.board.rows().enumerate() {
+            if self.ruler {
+                write!(f, "{:>width$} ", row, width = row_label_width)?;
+            }
+            for (col, cell) in cells.iter().enumerate() {
+                let glyph = cell.to_char();
+                match self.highlights.get(&Point::from_row_col(row, col)) {
+                    None => f.write_char(glyph)?,
+                    Some((color, highlight_glyph)) => {
+                        let glyph = highlight_glyph.unwrap_or(glyph);
+                        if self.ansi {
+                            write!(f, "\x1b[{}m{}\x1b[0m", color.ansi_code(), glyph)?;
+                        } else {
+                            f.write_char(glyph)?;
+                        }
+                    }
+                }
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_board() {
+        let board: Board<i8> = Board::new(vec![vec![0, 1], vec![8, 9]]);
+        assert_eq!(board.to_string(), "01\n89\n");
+        assert_eq!(board.renderer().render(), board.render());
+    }
+
+    #[test]
+    fn highlight_with_glyph() {
+        let board = Board::<char>::from_text("...\n.#.\n...\n");
+        let path = [Point::new(0, 0), Point::new(1, 0), Point::new(2, 0)];
+
+        let text = board
+            .renderer()
+            .highlight(Highlight::new(path, Color::Yellow).glyph('X'))
+            .highlight(Highlight::new([Point::new(1, 1)], Color::Red))
+            .render();
+        assert_eq!(text, "XXX\n.#.\n...\n");
+    }
+
+    #[test]
+    fn later_highlight_wins() {
+        let board = Board::<char>::from_text("..\n");
+
+        let text = board
+            .renderer()
+            .highlight(Highlight::new([Point::new(0, 0), Point::new(1, 0)], Color::Red).glyph('a'))
+            .highlight(Highlight::new([Point::new(1, 0)], Color::Blue).glyph('b'))
+            .render();
+        assert_eq!(text, "ab\n");
+    }
+
+    #[test]
+    fn ansi_colours() {
+        let board = Board::<char>::from_text("ab\n");
+
+        let text = board
+            .renderer()
+            .highlight(Highlight::new([Point::new(1, 0)], Color::Green))
+            .ansi(true)
+            .render();
+        assert_eq!(text, "a\x1b[1;32mb\x1b[0m\n");
+    }
+
+    #[test]
+    fn ruler_numbers_rows_and_columns() {
+        let board = Board::filled(11, 12, '.');
+
+        let text = board.renderer().ruler().render();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "   000000000011");
+        assert_eq!(lines[1], "   012345678901");
+        assert_eq!(lines[2], " 0 ............");
+        assert_eq!(lines[12], "10 ............");
+    }
+
+    #[test]
+    fn ruler_of_small_board() {
+        let board = Board::<char>::from_text("ab\ncd\n");
+        assert_eq!(board.renderer().ruler().render(), "  01\n0 ab\n1 cd\n");
+    }
+}
@@ -0,0 +1,212 @@
+//! Generating the files of a new day from the `src/bin/NN.rs` template.
+
+use anyhow::*;
+
+const TEMPLATE: &str = include_str!("bin/NN.rs");
+
+const TEST_INPUT_PLACEHOLDER: &str = "<TEST-INPUT>\n\"; // TODO: Add the test input";
+const EXPECTED_ANSWER_PLACEHOLDER: &str =
+    "        // TODO: Set the expected answer for the test input\n        assert_eq!(1, ";
+
+/// Example from the puzzle text with its expected Part 1 answer.
+pub struct Example<'a> {
+    pub input: &'a str,
+    pub answer: Option<&'a str>,
+}
+
+/// Normalize a day number, e.g. `"7"` to `"07"`.
+pub fn day_number(day: &str) -> Result<String> {
+    match day.parse::<u8>() {
+        Result::Ok(number @ 1..=25) => Ok(format!("{:02}", number)),
+        _ => Err(anyhow!("Day must be a number from 1 to 25, got '{}'", day)),
+    }
+}
+
+/// Source of `src/days/dayNN.rs`: the template without `main()`, with the day filled in
+/// and optionally the example test ready to run.
+pub fn day_module(day: &str, example: Option<&Example>) -> Result<String> {
+    let mut module = TEMPLATE.to_string();
+    replace(
+        &mut module,
+        "use adv_code_2024::solution::{run, Solution};",
+        "use crate::solution::Solution;",
+    )?;
+    replace(&mut module, "struct DayNN;", "pub struct DayNN;")?;
+    replace(
+        &mut module,
+        "\"NN\"; // TODO: Fill the day",
+        &format!("\"{}\";", day),
+    )?;
+    replace(
+        &mut module,
+        "fn main() -> Result<()> {\n    run::<DayNN>()\n}\n\n",
+        "",
+    )?;
+    replace(
+        &mut module,
+        "use adv_code_2024::solve_example;",
+        "use crate::solve_example;",
+    )?;
+
+    if let Some(example) = example {
+        replace(
+            &mut module,
+            TEST_INPUT_PLACEHOLDER,
+            &format!("{}\n\";", escape_string_literal(example.input.trim_end())),
+        )?;
+        if let Some(answer) = example.answer {
+            replace(
+                &mut module,
+                EXPECTED_ANSWER_PLACEHOLDER,
+                &format!("        assert_eq!({}, ", answer),
+            )?;
+        }
+    }
+
+    Ok(module.replace("DayNN", &format!("Day{}", day)))
+}
+
+/// Source of `src/bin/NN.rs` running the day registered in the library.
+pub fn day_binary(day: &str) -> String {
+    format!(
+        "\
+use adv_code_2024::days::day{day}::Day{day};
+use adv_code_2024::solution::run;
+use anyhow::*;
+
+fn main() -> Result<()> {{
+    run::<Day{day}>()
+}}
+"
+    )
+}
+
+/// Add the day module and its registry entry to the source of `src/days/mod.rs`,
+/// keeping both lists sorted by day.
+pub fn register_day(days_mod: &str, day: &str) -> Result<String> {
+    let module_line = format!("pub mod day{};", day);
+    let entry_line = format!("        DaySolution::of::<day{day}::Day{day}>(),");
+    if days_mod.lines().any(|line| line == module_line) {
+        return Err(anyhow!("Day {} is already registered", day));
+    }
+
+    let mut lines: Vec<String> = days_mod.lines().map(String::from).collect();
+    insert_sorted(&mut lines, "pub mod day", module_line)?;
+    insert_sorted(&mut lines, "        DaySolution::of::<day", entry_line)?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Insert `new_line` into the consecutive block of lines starting with `prefix`.
+fn insert_sorted(lines: &mut Vec<String>, prefix: &str, new_line: String) -> Result<()> {
+    let block_start = lines
+        .iter()
+        .position(|line| line.starts_with(prefix))
+        .ok_or_else(|| anyhow!("Can't find lines starting with '{}'", prefix.trim()))?;
+    let block_end = lines[block_start..]
+        .iter()
+        .position(|line| !line.starts_with(prefix))
+        .map_or(lines.len(), |offset| block_start + offset);
+
+    let index = lines[block_start..block_end]
+        .iter()
+        .position(|line| *line > new_line)
+        .map_or(block_end, |offset| block_start + offset);
+    lines.insert(index, new_line);
+    Ok(())
+}
+
+fn replace(source: &mut String, from: &str, to: &str) -> Result<()> {
+    if !source.contains(from) {
+        return Err(anyhow!("The template doesn't contain '{}'", from));
+    }
+    *source = source.replacen(from, to, 1);
+    Ok(())
+}
+
+/// Escape text to be put after `"\` in a Rust string literal.
+fn escape_string_literal(text: &str) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('"', "\\\"");
+    // Whitespace right after a line continuation would be skipped by the compiler
+    match escaped.strip_prefix(' ') {
+        Some(rest) => format!("\\x20{}", rest),
+        None => escaped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS_MOD: &str = "\
+use crate::solution::DaySolution;
+
+pub mod day01;
+pub mod day15;
+
+pub fn all() -> Vec<DaySolution> {
+    vec![
+        DaySolution::of::<day01::Day01>(),
+        DaySolution::of::<day15::Day15>(),
+    ]
+}
+";
+
+    #[test]
+    fn normalize_day_number() {
+        assert_eq!(day_number("7").unwrap(), "07");
+        assert_eq!(day_number("16").unwrap(), "16");
+        assert!(day_number("26").is_err());
+        assert!(day_number("NN").is_err());
+    }
+
+    #[test]
+    fn day_module_from_template() {
+        let module = day_module("16", None).unwrap();
+        assert!(module.contains("pub struct Day16;"));
+        assert!(module.contains("const DAY: &'static str = \"16\";"));
+        assert!(module.contains("use crate::solution::Solution;"));
+        assert!(!module.contains("fn main()"));
+        assert!(!module.contains("NN"));
+    }
+
+    #[test]
+    fn day_module_with_example() {
+        let example = Example {
+            input: "a \"b\"\nc\n",
+            answer: Some("42"),
+        };
+        let module = day_module("16", Some(&example)).unwrap();
+        assert!(module.contains("const TEST: &str = \"\\\na \\\"b\\\"\nc\n\";"));
+        assert!(module.contains("assert_eq!(42, solve_example(Day16::part1, TEST));"));
+        assert!(!module.contains("TODO: Set the expected answer"));
+    }
+
+    #[test]
+    fn keep_leading_whitespace_of_example() {
+        assert_eq!(escape_string_literal("  #"), "\\x20 #");
+    }
+
+    #[test]
+    fn register_day_in_order() {
+        let days_mod = register_day(DAYS_MOD, "07").unwrap();
+        assert!(days_mod.contains("pub mod day01;\npub mod day07;\npub mod day15;\n"));
+        assert!(days_mod.contains(
+            "        DaySolution::of::<day01::Day01>(),\n        \
+             DaySolution::of::<day07::Day07>(),\n        \
+             DaySolution::of::<day15::Day15>(),\n"
+        ));
+    }
+
+    #[test]
+    fn register_last_day() {
+        let days_mod = register_day(DAYS_MOD, "25").unwrap();
+        assert!(days_mod.contains("pub mod day15;\npub mod day25;\n"));
+        assert!(days_mod.contains("Day15>(),\n        DaySolution::of::<day25::Day25>(),\n    ]"));
+    }
+
+    #[test]
+    fn reject_registered_day() {
+        assert!(register_day(DAYS_MOD, "15").is_err());
+    }
+}
@@ -0,0 +1,385 @@
+use crate::board_matrix::{Board, Move, Point};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+pub fn bfs_distances<T>(
+    board: &Board<T>,
+    start: &Point,
+    can_move: impl Fn(&T, &T) -> bool,
+) -> Board<Option<usize>> {
+    let mut distances = Board::filled(board.n_rows, board.n_cols, None);
+    if !board.contains(start) {
+        return distances;
+    }
+
+    distances[start] = Some(0);
+    let mut queue = VecDeque::from([(*start, 0)]);
+    while let Some((point, distance)) = queue.pop_front() {
+        let value = &board[&point];
+        for (neighbor, neighbor_value) in board.neighbors4(&point) {
+            if distances[&neighbor].is_none() && can_move(value, neighbor_value) {
+                distances[&neighbor] = Some(distance + 1);
+                queue.push_back((neighbor, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pose {
+    pub position: Point,
+    pub direction: Move,
+}
+
+impl Pose {
+    pub fn successors<T>(
+        &self,
+        board: &Board<T>,
+        passable: impl Fn(&T) -> bool,
+        step_cost: u64,
+        turn_cost: u64,
+    ) -> Vec<(Pose, u64)> {
+        let Pose {
+            position,
+            direction,
+        } = self;
+        let mut successors = Vec::with_capacity(3);
+
+        let next_position = *position + direction.coordinates();
+        if board.get(&next_position).is_some_and(passable) {
+            successors.push((
+                Pose {
+                    position: next_position,
+                    direction: *direction,
+                },
+                step_cost,
+            ));
+        }
+        for turned in [direction.turn_left(), direction.turn_right()] {
+            successors.push((
+                Pose {
+                    position: *position,
+                    direction: turned,
+                },
+                turn_cost,
+            ));
+        }
+        successors
+    }
+}
+
+#[derive(Debug)]
+pub struct ShortestPaths<S> {
+    costs: HashMap<S, u64>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    pub fn cost(&self, state: &S) -> Option<u64> {
+        self.costs.get(state).copied()
+    }
+
+    /// The number of paths can grow exponentially, prefer [`ShortestPaths::states_on_paths_to`]
+    /// when only the visited states matter.
+    pub fn paths_to(&self, target: &S) -> Vec<Vec<S>> {
+        if !self.costs.contains_key(target) {
+            return Vec::new();
+        }
+
+        let mut paths = Vec::new();
+        // Partial paths going backwards from the target
+        let mut stack = vec![vec![target.clone()]];
+        while let Some(mut path) = stack.pop() {
+            match self.predecessors.get(&path[path.len() - 1]) {
+                Some(predecessors) => {
+                    for predecessor in predecessors {
+                        let mut longer_path = path.clone();
+                        longer_path.push(predecessor.clone());
+                        stack.push(longer_path);
+                    }
+                }
+                None => {
+                    path.reverse();
+                    paths.push(path);
+                }
+            }
+        }
+        paths
+    }
+
+    pub fn states_on_paths_to(&self, target: &S) -> HashSet<S> {
+        let mut states = HashSet::new();
+        if !self.costs.contains_key(target) {
+            return states;
+        }
+
+        let mut stack = vec![target.clone()];
+        while let Some(state) = stack.pop() {
+            if let Some(predecessors) = self.predecessors.get(&state) {
+                for predecessor in predecessors {
+                    if !states.contains(predecessor) {
+                        stack.push(predecessor.clone());
+                    }
+                }
+            }
+            states.insert(state);
+        }
+        states
+    }
+}
+
+/// The costs are expected to be positive, a cycle of zero-cost moves breaks the path
+/// reconstruction.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut costs: HashMap<S, u64> = HashMap::new();
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    // The heap refers to the states by their index, so the states don't have to be ordered
+    let mut states: Vec<S> = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if costs.insert(start.clone(), 0).is_none() {
+            heap.push(Reverse((0, states.len())));
+            states.push(start);
+        }
+    }
+
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        let state = states[index].clone();
+        if costs[&state] < cost {
+            // The state was reached more cheaply after this entry was queued
+            continue;
+        }
+
+        for (next_state, move_cost) in successors(&state) {
+            let next_cost = cost + move_cost;
+            match costs.get(&next_state) {
+                Some(&known_cost) if known_cost < next_cost => {}
+                Some(&known_cost) if known_cost == next_cost => {
+                    let next_predecessors = predecessors.entry(next_state).or_default();
+                    if !next_predecessors.contains(&state) {
+                        next_predecessors.push(state.clone());
+                    }
+                }
+                _ => {
+                    costs.insert(next_state.clone(), next_cost);
+                    predecessors.insert(next_state.clone(), vec![state.clone()]);
+                    heap.push(Reverse((next_cost, states.len())));
+                    states.push(next_state);
+                }
+            }
+        }
+    }
+
+    ShortestPaths {
+        costs,
+        predecessors,
+    }
+}
+
+/// The Manhattan distance is used as the heuristic, so the path is the cheapest one as long
+/// as every step costs at least 1.
+pub fn astar<T>(
+    board: &Board<T>,
+    start: &Point,
+    goal: &Point,
+    step_cost: impl Fn(&T, &T) -> Option<u64>,
+) -> Option<(u64, Vec<Point>)> {
+    if !board.contains(start) || !board.contains(goal) {
+        return None;
+    }
+
+    let mut costs: HashMap<Point, u64> = HashMap::from([(*start, 0)]);
+    let mut came_from: HashMap<Point, Point> = HashMap::new();
+    let mut points: Vec<Point> = vec![*start];
+    let mut heap = BinaryHeap::from([Reverse((manhattan(start, goal), 0, 0))]);
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let point = points[index];
+        if point == *goal {
+            return Some((cost, reconstruct_path(&came_from, point)));
+        }
+        if costs[&point] < cost {
+            continue;
+        }
+
+        let value = &board[&point];
+        for (neighbor, neighbor_value) in board.neighbors4(&point) {
+            let Some(move_cost) = step_cost(value, neighbor_value) else {
+                continue;
+            };
+            let next_cost = cost + move_cost;
+            if costs
+                .get(&neighbor)
+                .is_some_and(|&known| known <= next_cost)
+            {
+                continue;
+            }
+            costs.insert(neighbor, next_cost);
+            came_from.insert(neighbor, point);
+            heap.push(Reverse((
+                next_cost + manhattan(&neighbor, goal),
+                next_cost,
+                points.len(),
+            )));
+            points.push(neighbor);
+        }
+    }
+    None
+}
+
+fn reconstruct_path(came_from: &HashMap<Point, Point>, end: Point) -> Vec<Point> {
+    let mut path = vec![end];
+    while let Some(previous) = came_from.get(&path[path.len() - 1]) {
+        path.push(*previous);
+    }
+    path.reverse();
+    path
+}
+
+fn manhattan(a: &Point, b: &Point) -> u64 {
+    u64::from(a.manhattan(*b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    fn find(board: &Board<char>, c: char) -> Point {
+        board
+            .iter()
+            .find(|(_, value)| **value == c)
+            .map(|(point, _)| point)
+            .unwrap()
+    }
+
+    fn open(_: &char, to: &char) -> bool {
+        *to != '#'
+    }
+
+    #[test]
+    fn bfs_distance_map() {
+        let board: Board<char> = Board::from_text("S.#\n#..\n..#\n");
+        let distances = bfs_distances(&board, &Point { x: 0, y: 0 }, open);
+
+        assert_eq!(distances[Point { x: 2, y: 1 }], Some(3));
+        assert_eq!(distances[Point { x: 0, y: 2 }], Some(4));
+        assert_eq!(distances[Point { x: 2, y: 0 }], None);
+    }
+
+    #[test]
+    fn dijkstra_with_turn_costs() {
+        let maze: Board<char> = Board::from_text(MAZE);
+        let start = Pose {
+            position: find(&maze, 'S'),
+            direction: Move::Right,
+        };
+        let paths = dijkstra([start], |pose| {
+            pose.successors(&maze, |c| *c != '#', 1, 1000)
+        });
+
+        let end = find(&maze, 'E');
+        let end_poses: Vec<Pose> = Move::cardinals()
+            .map(|direction| Pose {
+                position: end,
+                direction,
+            })
+            .collect();
+        let lowest_cost = end_poses.iter().filter_map(|pose| paths.cost(pose)).min();
+        assert_eq!(lowest_cost, Some(7036));
+
+        let tiles: HashSet<Point> = end_poses
+            .iter()
+            .filter(|pose| paths.cost(pose) == lowest_cost)
+            .flat_map(|pose| paths.states_on_paths_to(pose))
+            .map(|pose| pose.position)
+            .collect();
+        assert_eq!(tiles.len(), 45);
+    }
+
+    #[test]
+    fn all_shortest_paths() {
+        let board = Board::filled(2, 3, '.');
+        let paths = dijkstra([Point { x: 0, y: 0 }], |point| {
+            board
+                .neighbors4(point)
+                .map(|(neighbor, _)| (neighbor, 1))
+                .collect::<Vec<_>>()
+        });
+
+        let all_paths = paths.paths_to(&Point { x: 2, y: 1 });
+        assert_eq!(all_paths.len(), 3);
+        for path in all_paths {
+            assert_eq!(path.len(), 4);
+            assert_eq!(path[0], Point { x: 0, y: 0 });
+            assert_eq!(path[3], Point { x: 2, y: 1 });
+        }
+    }
+
+    #[test]
+    fn parallel_moves_dont_duplicate_paths() {
+        // Two moves of the same cost from every state to the next one
+        let paths = dijkstra([0], |&state| {
+            if state < 2 {
+                vec![(state + 1, 1), (state + 1, 1)]
+            } else {
+                Vec::new()
+            }
+        });
+
+        assert_eq!(paths.paths_to(&2), vec![vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn astar_finds_shortest_path() {
+        let maze: Board<char> = Board::from_text(MAZE);
+        let (start, end) = (find(&maze, 'S'), find(&maze, 'E'));
+        let step_cost = |from: &char, to: &char| open(from, to).then_some(1);
+
+        let (cost, path) = astar(&maze, &start, &end, step_cost).unwrap();
+        let distances = bfs_distances(&maze, &start, open);
+        assert_eq!(Some(cost as usize), distances[&end]);
+        assert_eq!(path.len() as u64, cost + 1);
+        assert_eq!((&path[0], &path[path.len() - 1]), (&start, &end));
+    }
+
+    #[test]
+    fn astar_without_path() {
+        let board: Board<char> = Board::from_text("S#.\n.#.\n");
+        let step_cost = |from: &char, to: &char| open(from, to).then_some(1);
+        let path = astar(
+            &board,
+            &Point { x: 0, y: 0 },
+            &Point { x: 2, y: 0 },
+            step_cost,
+        );
+        assert_eq!(path, None);
+    }
+}
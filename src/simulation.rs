@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// 0 is the initial state.
+    pub start: usize,
+    pub length: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome<S> {
+    Terminated { steps: usize, state: S },
+    Cycle(Cycle),
+}
+
+pub trait Simulation {
+    type State: Clone + Eq + Hash;
+
+    fn initial_state(&self) -> Self::State;
+
+    fn step(&self, state: &Self::State) -> Self::State;
+
+    /// A terminal state has no next state.
+    fn is_terminal(&self, _state: &Self::State) -> bool {
+        false
+    }
+
+    /// Never ends when the simulation runs into a cycle.
+    fn states(&self) -> States<'_, Self>
+    where
+        Self: Sized,
+    {
+        States {
+            simulation: self,
+            next: Some(self.initial_state()),
+        }
+    }
+
+    /// Remembers every state, see [`Simulation::run_brent`] when the states are large.
+    fn run(&self) -> Outcome<Self::State>
+    where
+        Self: Sized,
+    {
+        let mut seen: HashMap<Self::State, usize> = HashMap::new();
+        for (index, state) in self.states().enumerate() {
+            if let Some(&start) = seen.get(&state) {
+                return Outcome::Cycle(Cycle {
+                    start,
+                    length: index - start,
+                });
+            }
+            if self.is_terminal(&state) {
+                return Outcome::Terminated {
+                    steps: index,
+                    state,
+                };
+            }
+            seen.insert(state, index);
+        }
+        unreachable!("The states end only with a terminal state")
+    }
+
+    /// Brent's algorithm keeps only two states in memory but steps through the states about
+    /// three times.
+    fn run_brent(&self) -> Outcome<Self::State>
+    where
+        Self: Sized,
+    {
+        let initial = self.initial_state();
+        if self.is_terminal(&initial) {
+            return Outcome::Terminated {
+                steps: 0,
+                state: initial,
+            };
+        }
+
+        // Find the length with the hare searching for the tortoise in growing windows
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise = initial.clone();
+        let mut hare = self.step(&initial);
+        let mut hare_steps = 1;
+        while tortoise != hare {
+            if self.is_terminal(&hare) {
+                return Outcome::Terminated {
+                    steps: hare_steps,
+                    state: hare,
+                };
+            }
+            if power == length {
+                tortoise = hare.clone();
+                power *= 2;
+                length = 0;
+            }
+            hare = self.step(&hare);
+            hare_steps += 1;
+            length += 1;
+        }
+
+        // The first state which equals the state `length` steps later starts the cycle
+        let mut tortoise = initial.clone();
+        let mut hare = initial;
+        for _ in 0..length {
+            hare = self.step(&hare);
+        }
+        let mut start = 0;
+        while tortoise != hare {
+            tortoise = self.step(&tortoise);
+            hare = self.step(&hare);
+            start += 1;
+        }
+        Outcome::Cycle(Cycle { start, length })
+    }
+
+    /// Skips the repetitions of a cycle, or stops at the terminal state.
+    fn state_after(&self, steps: u64) -> Self::State
+    where
+        Self: Sized,
+    {
+        let needed_steps = match self.run_brent() {
+            Outcome::Terminated {
+                steps: last_step,
+                state,
+            } => {
+                if steps >= last_step as u64 {
+                    return state;
+                }
+                steps
+            }
+            Outcome::Cycle(Cycle { start, length }) => {
+                let start = start as u64;
+                if steps < start {
+                    steps
+                } else {
+                    start + (steps - start) % length as u64
+                }
+            }
+        };
+        (0..needed_steps).fold(self.initial_state(), |state, _| self.step(&state))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct States<'a, S: Simulation> {
+    simulation: &'a S,
+    next: Option<S::State>,
+}
+
+impl<S: Simulation> Iterator for States<'_, S> {
+    type Item = S::State;
+
+    fn next(&mut self) -> Option<S::State> {
+        let state = self.next.take()?;
+        if !self.simulation.is_terminal(&state) {
+            self.next = Some(self.simulation.step(&state));
+        }
+        Some(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pseudo-random numbers `x -> (x * x + 1) % modulus`, which always end in a cycle.
+    struct Squares {
+        seed: u64,
+        modulus: u64,
+    }
+
+    impl Simulation for Squares {
+        type State = u64;
+
+        fn initial_state(&self) -> u64 {
+            self.seed
+        }
+
+        fn step(&self, x: &u64) -> u64 {
+            (x * x + 1) % self.modulus
+        }
+    }
+
+    /// Counter from `from` down to 0.
+    struct Countdown {
+        from: u32,
+    }
+
+    impl Simulation for Countdown {
+        type State = u32;
+
+        fn initial_state(&self) -> u32 {
+            self.from
+        }
+
+        fn step(&self, n: &u32) -> u32 {
+            n - 1
+        }
+
+        fn is_terminal(&self, n: &u32) -> bool {
+            *n == 0
+        }
+    }
+
+    #[test]
+    fn find_cycle() {
+        // 3, 10, 2, 5, 4, 6, 4, 6...
+        let squares = Squares {
+            seed: 3,
+            modulus: 11,
+        };
+        let cycle = Cycle {
+            start: 4,
+            length: 2,
+        };
+
+        assert_eq!(squares.run(), Outcome::Cycle(cycle));
+        assert_eq!(squares.run_brent(), Outcome::Cycle(cycle));
+    }
+
+    #[test]
+    fn both_algorithms_agree() {
+        for seed in 0..50 {
+            let squares = Squares {
+                seed,
+                modulus: 1009,
+            };
+            assert_eq!(squares.run(), squares.run_brent(), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn cycle_from_the_initial_state() {
+        // 0, 1, 2, 0...
+        let squares = Squares {
+            seed: 0,
+            modulus: 5,
+        };
+        assert_eq!(
+            squares.run_brent(),
+            Outcome::Cycle(Cycle {
+                start: 0,
+                length: 3
+            })
+        );
+    }
+
+    #[test]
+    fn terminate() {
+        let countdown = Countdown { from: 4 };
+        let terminated = Outcome::Terminated { steps: 4, state: 0 };
+
+        assert_eq!(countdown.run(), terminated);
+        assert_eq!(countdown.run_brent(), terminated);
+        assert_eq!(countdown.states().collect::<Vec<_>>(), vec![4, 3, 2, 1, 0]);
+        assert_eq!(
+            Countdown { from: 0 }.run_brent(),
+            Outcome::Terminated { steps: 0, state: 0 }
+        );
+    }
+
+    #[test]
+    fn extrapolate_state() {
+        let squares = Squares {
+            seed: 3,
+            modulus: 11,
+        };
+
+        assert_eq!(squares.state_after(2), 2);
+        assert_eq!(squares.state_after(1_000_000_000), 4);
+        assert_eq!(squares.state_after(1_000_000_001), 6);
+        assert_eq!(Countdown { from: 4 }.state_after(1_000_000_000), 0);
+        assert_eq!(Countdown { from: 4 }.state_after(1), 3);
+    }
+}
@@ -0,0 +1,151 @@
+use anyhow::*;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+use std::time::Instant;
+
+use crate::bench::{self, BenchConfig, PartBenchmark};
+use crate::{open_personal_input, start_day};
+
+/// A solved (or partially solved) day of Advent of Code.
+///
+/// The puzzle input is parsed once by `parse` and both parts are solved from the parsed input,
+/// so parsing and solving can be timed separately. `part1` and `part2` do both steps at once,
+/// reading the input from a buffered reader. Days which don't have Part 2 solved yet can skip
+/// `solve_part2`, the default implementation returns an [`Unsolved`] error.
+pub trait Solution {
+    /// Two-digit number of the day, e.g. `"07"`.
+    const DAY: &'static str;
+
+    /// Puzzle input parsed from the text, shared by both parts.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse<R: BufRead>(reader: &mut R) -> Result<Self::Input>;
+
+    fn solve_part1(input: &Self::Input) -> Result<Self::Answer1>;
+
+    fn solve_part2(_input: &Self::Input) -> Result<Self::Answer2> {
+        Err(Error::new(Unsolved))
+    }
+
+    fn part1<R: BufRead>(reader: &mut R) -> Result<Self::Answer1> {
+        Self::solve_part1(&Self::parse(reader)?)
+    }
+
+    fn part2<R: BufRead>(reader: &mut R) -> Result<Self::Answer2> {
+        Self::solve_part2(&Self::parse(reader)?)
+    }
+}
+
+/// Error returned by a part which isn't solved yet.
+#[derive(Debug)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "This part is not solved yet")
+    }
+}
+
+impl std::error::Error for Unsolved {}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(anyhow!("Part must be 1 or 2, got '{}'", s)),
+        }
+    }
+}
+
+pub type PartFn = fn(&mut dyn BufRead) -> Result<String>;
+pub type BenchFn = fn(&str, Part, &BenchConfig) -> Result<PartBenchmark>;
+
+/// Type-erased entry of the registry of all days, see [`crate::days::all`].
+#[derive(Debug, Clone, Copy)]
+pub struct DaySolution {
+    pub day: &'static str,
+    pub part1: PartFn,
+    pub part2: PartFn,
+    pub bench: BenchFn,
+}
+
+impl DaySolution {
+    pub fn of<S: Solution>() -> Self {
+        DaySolution {
+            day: S::DAY,
+            part1: |mut reader| S::part1(&mut reader).map(|answer| answer.to_string()),
+            part2: |mut reader| S::part2(&mut reader).map(|answer| answer.to_string()),
+            bench: bench::benchmark::<S>,
+        }
+    }
+
+    pub fn solve(&self, part: Part, reader: &mut dyn BufRead) -> Result<String> {
+        match part {
+            Part::One => (self.part1)(reader),
+            Part::Two => (self.part2)(reader),
+        }
+    }
+
+    pub fn input_path(&self) -> String {
+        input_path(self.day)
+    }
+}
+
+pub fn input_path(day: &str) -> String {
+    format!("input/{}.txt", day)
+}
+
+/// Run both parts of the day against its personal input `input/NN.txt`.
+///
+/// A missing input is reported as skipped rather than an error.
+pub fn run<S: Solution>() -> Result<()> {
+    start_day(S::DAY);
+
+    let Some(mut input_file) = open_personal_input(S::DAY) else {
+        return Ok(());
+    };
+    let start = Instant::now();
+    let input = S::parse(&mut input_file)?;
+    println!("Parsing took {:?}", start.elapsed());
+
+    println!("=== Part 1 ===");
+    let start = Instant::now();
+    let result = S::solve_part1(&input)?;
+    println!("Result = {}  ({:?})", result, start.elapsed());
+
+    println!("\n=== Part 2 ===");
+    let start = Instant::now();
+    match S::solve_part2(&input) {
+        Result::Ok(result) => println!("Result = {}  ({:?})", result, start.elapsed()),
+        Err(e) if e.is::<Unsolved>() => println!("{}", e),
+        Err(e) => return Err(e),
+    }
+
+    Ok(())
+}
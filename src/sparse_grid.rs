@@ -0,0 +1,281 @@
+use crate::board_matrix::{Board, Move, Point};
+use std::collections::HashMap;
+use std::ops::Index;
+use std::sync::OnceLock;
+
+/// A missing cell plays the role of a point outside of a [`Board`].
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    /// Computed on the first use.
+    bounds: OnceLock<Option<(Point, Point)>>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: OnceLock::new(),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseGrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: Eq> Eq for SparseGrid<T> {}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid::default()
+    }
+
+    pub fn from_board(board: &Board<T>, keep: impl Fn(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        board
+            .iter()
+            .filter(|(_, value)| keep(value))
+            .map(|(point, value)| (point, value.clone()))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        self.cells.get(point)
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        self.cells.get_mut(point)
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.cells.contains_key(point)
+    }
+
+    pub fn set_value_from_point(&mut self, point: &Point, value: T) {
+        self.insert(*point, value);
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        // Growing the bounds is cheap, there's no need to compute them again
+        if let Some(Some((min, max))) = self.bounds.get_mut() {
+            *min = Point::new(min.x.min(point.x), min.y.min(point.y));
+            *max = Point::new(max.x.max(point.x), max.y.max(point.y));
+        } else {
+            self.bounds = OnceLock::new();
+        }
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: &Point) -> Option<T> {
+        let value = self.cells.remove(point);
+        if value.is_some() {
+            self.bounds = OnceLock::new();
+        }
+        value
+    }
+
+    /// In no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+
+    /// Inclusive corners, `None` for an empty grid.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        *self.bounds.get_or_init(|| {
+            let mut points = self.cells.keys();
+            let first = *points.next()?;
+            Some(points.fold((first, first), |(min, max), point| {
+                (
+                    Point::new(min.x.min(point.x), min.y.min(point.y)),
+                    Point::new(max.x.max(point.x), max.y.max(point.y)),
+                )
+            }))
+        })
+    }
+
+    pub fn neighbors4<'a>(&'a self, point: &Point) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        self.occupied_neighbors(point, &Move::CARDINALS)
+    }
+
+    pub fn neighbors8<'a>(&'a self, point: &Point) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        self.occupied_neighbors(point, &Move::ALL)
+    }
+
+    pub fn neighbors4_or_outside<'a>(
+        &'a self,
+        point: &Point,
+    ) -> impl Iterator<Item = (Point, Option<&'a T>)> + 'a {
+        let point = *point;
+        Move::CARDINALS.iter().map(move |direction| {
+            let neighbor = point + direction.coordinates();
+            (neighbor, self.get(&neighbor))
+        })
+    }
+
+    fn occupied_neighbors<'a>(
+        &'a self,
+        point: &Point,
+        directions: &'static [Move],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        let point = *point;
+        directions.iter().filter_map(move |direction| {
+            let neighbor = point + direction.coordinates();
+            Some((neighbor, self.get(&neighbor)?))
+        })
+    }
+
+    /// Also returns the point of the grid which became the top left cell of the board.
+    pub fn to_board(&self, empty: T) -> (Board<T>, Point)
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds() else {
+            return (Board::new(Vec::new()), Point::default());
+        };
+        let n_rows = (max.y - min.y + 1) as usize;
+        let n_cols = (max.x - min.x + 1) as usize;
+        let board = Board::from_fn(n_rows, n_cols, |row, col| {
+            let point = min + Point::from_row_col(row, col);
+            self.get(&point).unwrap_or(&empty).clone()
+        });
+        (board, min)
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+            bounds: OnceLock::new(),
+        }
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<T> Index<&Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: &Point) -> &T {
+        match self.get(point) {
+            Some(value) => value,
+            None => panic!("There is no cell at (x: {}, y: {})", point.x, point.y),
+        }
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        &self[&point]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_and_set_at_negative_points() {
+        let mut grid = SparseGrid::new();
+        grid.set_value_from_point(&Point::new(-5, 3), 'a');
+        grid.insert(Point::new(100, -7), 'b');
+
+        assert_eq!(grid.get(&Point::new(-5, 3)), Some(&'a'));
+        assert_eq!(grid[Point::new(100, -7)], 'b');
+        assert_eq!(grid.get(&Point::new(0, 0)), None);
+        assert_eq!(grid.len(), 2);
+    }
+
+    #[test]
+    fn bounds_follow_changes() {
+        let mut grid: SparseGrid<i32> = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(Point::new(2, 3), 1);
+        grid.insert(Point::new(-1, 5), 2);
+        assert_eq!(grid.bounds(), Some((Point::new(-1, 3), Point::new(2, 5))));
+
+        grid.insert(Point::new(4, 0), 3);
+        assert_eq!(grid.bounds(), Some((Point::new(-1, 0), Point::new(4, 5))));
+
+        grid.remove(&Point::new(-1, 5));
+        assert_eq!(grid.bounds(), Some((Point::new(2, 0), Point::new(4, 3))));
+    }
+
+    #[test]
+    fn bounds_shared_between_threads() {
+        let grid: SparseGrid<i32> = [(Point::new(2, 3), 1), (Point::new(-1, 5), 2)]
+            .into_iter()
+            .collect();
+
+        std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..2).map(|_| scope.spawn(|| grid.bounds())).collect();
+            for worker in workers {
+                let bounds = worker.join().unwrap();
+                assert_eq!(bounds, Some((Point::new(-1, 3), Point::new(2, 5))));
+            }
+        });
+    }
+
+    #[test]
+    fn neighbors_of_point() {
+        let grid: SparseGrid<char> = [(Point::new(0, 1), 'a'), (Point::new(1, 1), 'b')]
+            .into_iter()
+            .collect();
+
+        let neighbors: Vec<(Point, &char)> = grid.neighbors4(&Point::new(0, 0)).collect();
+        assert_eq!(neighbors, vec![(Point::new(0, 1), &'a')]);
+        assert_eq!(grid.neighbors8(&Point::new(0, 0)).count(), 2);
+        assert_eq!(
+            grid.neighbors4_or_outside(&Point::new(0, 0))
+                .filter(|(_, value)| value.is_none())
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn convert_to_and_from_board() {
+        let board: Board<char> = Board::from_text("..a\n...\nb..\n");
+
+        let grid = SparseGrid::from_board(&board, |c| *c != '.');
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(&Point::new(2, 0)), Some(&'a'));
+
+        let (dense, top_left) = grid.to_board('.');
+        assert_eq!(dense, board);
+        assert_eq!(top_left, Point::new(0, 0));
+    }
+
+    #[test]
+    fn board_of_points_with_offset() {
+        let grid: SparseGrid<char> = [(Point::new(-2, -1), '#'), (Point::new(-1, 0), '#')]
+            .into_iter()
+            .collect();
+
+        let (board, top_left) = grid.to_board('.');
+        assert_eq!(top_left, Point::new(-2, -1));
+        assert_eq!(board.render(), "#.\n.#\n");
+    }
+}
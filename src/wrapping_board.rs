@@ -0,0 +1,246 @@
+use crate::board_matrix::{Board, Move, MoveIterator, Point};
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrappingBoard<T> {
+    board: Board<T>,
+}
+
+impl<T> WrappingBoard<T> {
+    /// Panics on an empty board, which has no cell to wrap to.
+    pub fn new(board: Board<T>) -> Self {
+        assert!(
+            board.n_rows > 0 && board.n_cols > 0,
+            "A wrapping board can't be empty"
+        );
+        WrappingBoard { board }
+    }
+
+    pub fn filled(n_rows: usize, n_cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        WrappingBoard::new(Board::filled(n_rows, n_cols, value))
+    }
+
+    pub fn n_rows(&self) -> usize {
+        self.board.n_rows
+    }
+
+    pub fn n_cols(&self) -> usize {
+        self.board.n_cols
+    }
+
+    pub fn board(&self) -> &Board<T> {
+        &self.board
+    }
+
+    pub fn into_board(self) -> Board<T> {
+        self.board
+    }
+
+    pub fn wrap(&self, point: Point) -> Point {
+        self.board.wrap(point)
+    }
+
+    pub fn get(&self, point: &Point) -> &T {
+        &self.board[self.wrap(*point)]
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> &mut T {
+        let point = self.wrap(*point);
+        &mut self.board[point]
+    }
+
+    pub fn set_value_from_point(&mut self, point: &Point, value: T) {
+        *self.get_mut(point) = value;
+    }
+
+    /// Computed at once instead of step by step.
+    pub fn advance(&self, position: Point, velocity: Point, steps: i64) -> Point {
+        let wrap_axis = |position: i32, velocity: i32, size: usize| {
+            (position as i64 + velocity as i64 * steps).rem_euclid(size as i64) as i32
+        };
+        Point::new(
+            wrap_axis(position.x, velocity.x, self.n_cols()),
+            wrap_axis(position.y, velocity.y, self.n_rows()),
+        )
+    }
+
+    /// 0 top left, 1 top right, 2 bottom left, 3 bottom right. Points on the middle row or
+    /// column of an odd sized board aren't in any quadrant.
+    pub fn quadrant(&self, point: &Point) -> Option<usize> {
+        let point = self.wrap(*point);
+        let half = |position: i32, size: usize| {
+            let position = position as usize;
+            if size % 2 == 1 && position == size / 2 {
+                None
+            } else {
+                Some(usize::from(position >= size / 2))
+            }
+        };
+        let right = half(point.x, self.n_cols())?;
+        let bottom = half(point.y, self.n_rows())?;
+        Some(bottom * 2 + right)
+    }
+
+    pub fn quadrant_counts(&self, points: impl IntoIterator<Item = Point>) -> [usize; 4] {
+        let mut counts = [0; 4];
+        for point in points {
+            if let Some(quadrant) = self.quadrant(&point) {
+                counts[quadrant] += 1;
+            }
+        }
+        counts
+    }
+
+    pub fn neighbors4<'a>(&'a self, point: &Point) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        self.wrapped_neighbors(point, &Move::CARDINALS)
+    }
+
+    pub fn neighbors8<'a>(&'a self, point: &Point) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        self.wrapped_neighbors(point, &Move::ALL)
+    }
+
+    fn wrapped_neighbors<'a>(
+        &'a self,
+        point: &Point,
+        directions: &'static [Move],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        let point = *point;
+        directions.iter().map(move |direction| {
+            let neighbor = self.wrap(point + direction.coordinates());
+            (neighbor, &self.board[neighbor])
+        })
+    }
+
+    /// Never ends.
+    pub fn ray(&self, start: &Point, direction: &Move) -> MoveIterator<'_, T> {
+        MoveIterator::new(&self.board, &self.wrap(*start), direction).wrapping()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.board.iter()
+    }
+}
+
+impl<T> From<Board<T>> for WrappingBoard<T> {
+    fn from(board: Board<T>) -> Self {
+        WrappingBoard::new(board)
+    }
+}
+
+impl<T> Index<&Point> for WrappingBoard<T> {
+    type Output = T;
+
+    fn index(&self, point: &Point) -> &T {
+        self.get(point)
+    }
+}
+
+impl<T> IndexMut<&Point> for WrappingBoard<T> {
+    fn index_mut(&mut self, point: &Point) -> &mut T {
+        self.get_mut(point)
+    }
+}
+
+impl<T> Index<Point> for WrappingBoard<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(&point)
+    }
+}
+
+impl<T> IndexMut<Point> for WrappingBoard<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(&point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Robots of the AoC 2024 day 14 example as `(position, velocity)`.
+    const ROBOTS: [((i32, i32), (i32, i32)); 12] = [
+        ((0, 4), (3, -3)),
+        ((6, 3), (-1, -3)),
+        ((10, 3), (-1, 2)),
+        ((2, 0), (2, -1)),
+        ((0, 0), (1, 3)),
+        ((3, 0), (-2, -2)),
+        ((7, 6), (-1, -3)),
+        ((3, 0), (-1, -2)),
+        ((9, 3), (2, 3)),
+        ((7, 3), (-1, 2)),
+        ((2, 4), (2, -3)),
+        ((9, 5), (-3, -3)),
+    ];
+
+    #[test]
+    fn access_wraps_around() {
+        let mut board = WrappingBoard::new(Board::new(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+
+        assert_eq!(board[Point::new(-1, 0)], 3);
+        assert_eq!(board[Point::new(4, -3)], 5);
+        board[Point::new(-3, 2)] = 7;
+        assert_eq!(*board.get(&Point::new(0, 0)), 7);
+    }
+
+    #[test]
+    fn advance_in_one_step() {
+        let board = WrappingBoard::filled(7, 11, ());
+        let position = Point::new(2, 4);
+        let velocity = Point::new(2, -3);
+
+        assert_eq!(board.advance(position, velocity, 5), Point::new(1, 3));
+        let step_by_step = (0..5).fold(position, |position, _| board.wrap(position + velocity));
+        assert_eq!(board.advance(position, velocity, 5), step_by_step);
+        assert_eq!(
+            board.advance(position, velocity, 1_000_000_000_000),
+            board.advance(position, velocity, 1_000_000_000_000 % 77)
+        );
+    }
+
+    #[test]
+    fn count_robots_per_quadrant() {
+        let board = WrappingBoard::filled(7, 11, ());
+        let positions = ROBOTS
+            .iter()
+            .map(|&((x, y), (vx, vy))| board.advance(Point::new(x, y), Point::new(vx, vy), 100));
+
+        let counts = board.quadrant_counts(positions);
+        assert_eq!(counts, [1, 3, 4, 1]);
+        assert_eq!(counts.iter().product::<usize>(), 12);
+    }
+
+    #[test]
+    fn middle_lines_have_no_quadrant() {
+        let board = WrappingBoard::filled(3, 4, ());
+
+        assert_eq!(board.quadrant(&Point::new(0, 1)), None);
+        assert_eq!(board.quadrant(&Point::new(1, 0)), Some(0));
+        assert_eq!(board.quadrant(&Point::new(2, 2)), Some(3));
+        assert_eq!(board.quadrant(&Point::new(-1, -1)), Some(3));
+    }
+
+    #[test]
+    fn neighbors_wrap_around() {
+        let board = WrappingBoard::new(Board::new(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+
+        let neighbors: Vec<i32> = board
+            .neighbors4(&Point::new(0, 0))
+            .map(|(_, value)| *value)
+            .collect();
+        assert_eq!(neighbors, vec![3, 2, 4, 4]);
+        assert_eq!(board.neighbors8(&Point::new(0, 0)).count(), 8);
+
+        let ray: Vec<i32> = board
+            .ray(&Point::new(-1, 1), &Move::Right)
+            .take(4)
+            .map(|(_, value)| *value)
+            .collect();
+        assert_eq!(ray, vec![6, 4, 5, 6]);
+    }
+}
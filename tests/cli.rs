@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+const DAY15_EXAMPLE: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+/// Working directory with only `input/15.txt`, so every other day is skipped.
+fn workdir_with_day15_input(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-cli-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(dir.join("input")).unwrap();
+    std::fs::write(dir.join("input/15.txt"), DAY15_EXAMPLE).unwrap();
+    dir
+}
+
+#[test]
+fn run_all_skips_unsolved_part() {
+    let dir = workdir_with_day15_input("run-all");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "all", "--part", "2"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(
+        stdout.contains("Day 15\n  Part 2: not solved yet"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn run_single_unsolved_part_fails() {
+    let dir = workdir_with_day15_input("run-one");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "15", "--part", "2"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+}